@@ -8,7 +8,7 @@ use typst::diag::{bail, Severity, SourceDiagnostic, StrResult};
 use typst::doc::Document;
 use typst::eval::{eco_format, Tracer};
 use typst::geom::Color;
-use typst::syntax::{FileId, Source};
+use typst::syntax::{FileId, Source, Span};
 use typst::World;
 
 use crate::args::{CompileCommand, DiagnosticFormat};
@@ -48,12 +48,12 @@ pub fn compile_once(
 
     let mut tracer = Tracer::default();
     let result = typst::compile(world, &mut tracer);
-    let warnings = tracer.warnings();
 
     match result {
         // Export the PDF / PNG.
         Ok(document) => {
-            export(&document, command)?;
+            export(&document, command, &mut tracer)?;
+            let warnings = tracer.warnings();
             let duration = start.elapsed();
 
             tracing::info!("Compilation succeeded in {duration:?}");
@@ -77,6 +77,7 @@ pub fn compile_once(
         Err(errors) => {
             set_failed();
             tracing::info!("Compilation failed");
+            let warnings = tracer.warnings();
 
             if watching {
                 Status::Error.print(command).unwrap();
@@ -96,7 +97,11 @@ pub fn compile_once(
 }
 
 /// Export into the target format.
-fn export(document: &Document, command: &CompileCommand) -> StrResult<()> {
+fn export(
+    document: &Document,
+    command: &CompileCommand,
+    tracer: &mut Tracer,
+) -> StrResult<()> {
     match command.output().extension() {
         Some(ext) if ext.eq_ignore_ascii_case("png") => {
             export_image(document, command, ImageExportFormat::Png)
@@ -104,14 +109,18 @@ fn export(document: &Document, command: &CompileCommand) -> StrResult<()> {
         Some(ext) if ext.eq_ignore_ascii_case("svg") => {
             export_image(document, command, ImageExportFormat::Svg)
         }
-        _ => export_pdf(document, command),
+        _ => export_pdf(document, command, tracer),
     }
 }
 
 /// Export to a PDF.
-fn export_pdf(document: &Document, command: &CompileCommand) -> StrResult<()> {
+fn export_pdf(
+    document: &Document,
+    command: &CompileCommand,
+    tracer: &mut Tracer,
+) -> StrResult<()> {
     let output = command.output();
-    let buffer = typst::export::pdf(document);
+    let buffer = typst::export::pdf(document, tracer);
     fs::write(output, buffer).map_err(|_| "failed to write PDF file")?;
     Ok(())
 }
@@ -208,19 +217,16 @@ pub fn print_diagnostics(
                 .map(|e| (eco_format!("hint: {e}")).into())
                 .collect(),
         )
-        .with_labels(vec![Label::primary(
-            diagnostic.span.id(),
-            world.range(diagnostic.span),
-        )]);
+        .with_labels(label(world, diagnostic.span).into_iter().collect());
 
         term::emit(&mut w, &config, world, &diag)?;
 
         // Stacktrace-like helper diagnostics.
         for point in &diagnostic.trace {
             let message = point.v.to_string();
-            let help = Diagnostic::help().with_message(message).with_labels(vec![
-                Label::primary(point.span.id(), world.range(point.span)),
-            ]);
+            let help = Diagnostic::help()
+                .with_message(message)
+                .with_labels(label(world, point.span).into_iter().collect());
 
             term::emit(&mut w, &config, world, &help)?;
         }
@@ -229,6 +235,13 @@ pub fn print_diagnostics(
     Ok(())
 }
 
+/// Create a label for a span.
+///
+/// Returns `None` for detached spans, which don't point into any file.
+fn label(world: &SystemWorld, span: Span) -> Option<Label<FileId>> {
+    (!span.is_detached()).then(|| Label::primary(span.id(), world.range(span)))
+}
+
 impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
     type FileId = FileId;
     type Name = FileId;
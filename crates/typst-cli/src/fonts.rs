@@ -5,7 +5,6 @@ use std::path::{Path, PathBuf};
 
 use memmap2::Mmap;
 use typst::diag::StrResult;
-use typst::eval::Bytes;
 use typst::font::{Font, FontBook, FontInfo, FontVariant};
 use walkdir::WalkDir;
 
@@ -81,39 +80,14 @@ impl FontSearcher {
     /// Add fonts that are embedded in the binary.
     #[cfg(feature = "embed-fonts")]
     fn add_embedded(&mut self) {
-        let mut process = |bytes: &'static [u8]| {
-            let buffer = Bytes::from_static(bytes);
-            for (i, font) in Font::iter(buffer).enumerate() {
-                self.book.push(font.info().clone());
-                self.fonts.push(FontSlot {
-                    path: PathBuf::new(),
-                    index: i as u32,
-                    font: OnceCell::from(Some(font)),
-                });
-            }
-        };
-
-        macro_rules! add {
-            ($filename:literal) => {
-                process(include_bytes!(concat!("../../../assets/fonts/", $filename)));
-            };
+        for font in typst_library::embedded_fonts() {
+            self.book.push(font.info().clone());
+            self.fonts.push(FontSlot {
+                path: PathBuf::new(),
+                index: font.index(),
+                font: OnceCell::from(Some(font)),
+            });
         }
-
-        // Embed default fonts.
-        add!("LinLibertine_R.ttf");
-        add!("LinLibertine_RB.ttf");
-        add!("LinLibertine_RBI.ttf");
-        add!("LinLibertine_RI.ttf");
-        add!("NewCMMath-Book.otf");
-        add!("NewCMMath-Regular.otf");
-        add!("NewCM10-Regular.otf");
-        add!("NewCM10-Bold.otf");
-        add!("NewCM10-Italic.otf");
-        add!("NewCM10-BoldItalic.otf");
-        add!("DejaVuSansMono.ttf");
-        add!("DejaVuSansMono-Bold.ttf");
-        add!("DejaVuSansMono-Oblique.ttf");
-        add!("DejaVuSansMono-BoldOblique.ttf");
     }
 
     /// Search for fonts in the linux system font directories.
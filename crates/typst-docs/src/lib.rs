@@ -40,11 +40,15 @@ static FONTS: Lazy<(Prehashed<FontBook>, Vec<Font>)> = Lazy::new(|| {
 
 static LIBRARY: Lazy<Prehashed<Library>> = Lazy::new(|| {
     let mut lib = typst_library::build();
-    // Hack for documenting the `mix` function in the color module.
+    // Hack for documenting the `mix` and `linear` functions in the color and
+    // gradient modules.
     // Will be superseded by proper associated functions.
     lib.global
         .scope_mut()
         .define("mix", typst_library::compute::mix_func());
+    lib.global
+        .scope_mut()
+        .define("linear", typst_library::compute::linear_func());
     lib.styles
         .set(PageElem::set_width(Smart::Custom(Abs::pt(240.0).into())));
     lib.styles.set(PageElem::set_height(Smart::Auto));
@@ -981,6 +985,8 @@ const TYPE_ORDER: &[&str] = &[
     "relative length",
     "fraction",
     "color",
+    "gradient",
+    "pattern",
     "datetime",
     "string",
     "bytes",
@@ -0,0 +1,117 @@
+//! Building content from Rust.
+//!
+//! Every element has a constructor for its required fields and `with_*`
+//! methods for its settable ones, e.g. `HeadingElem::new(body).with_level(2)`.
+//! The functions in this module wrap the most common elements for host
+//! programs that generate documents from data instead of markup. The result
+//! can be typeset with [`typst::compile_content`].
+//!
+//! ```ignore
+//! use typst_library::builder::*;
+//!
+//! let report = sequence([
+//!     heading(1, text("Report")),
+//!     text("All systems nominal."),
+//!     parbreak(),
+//!     table(2, [text("CPU"), text("12%"), text("Memory"), text("3 GB")]),
+//! ]);
+//! ```
+
+use std::num::NonZeroUsize;
+
+use ecow::EcoString;
+use typst::eval::Bytes;
+use typst::geom::{Abs, Rel, Smart};
+use typst::model::{Content, Styles};
+
+use crate::compute::Readable;
+use crate::layout::{
+    EnumElem, EnumItem, ListElem, ListItem, Margin, PageElem, PagebreakElem,
+    ParbreakElem, Sizing, TableElem, TrackSizings,
+};
+use crate::meta::HeadingElem;
+use crate::text::{EmphElem, SpaceElem, StrongElem, TextElem};
+use crate::visualize::ImageElem;
+
+/// Join content into a sequence.
+pub fn sequence(children: impl IntoIterator<Item = Content>) -> Content {
+    Content::sequence(children)
+}
+
+/// A run of text.
+pub fn text(text: impl Into<EcoString>) -> Content {
+    TextElem::packed(text)
+}
+
+/// A space between words.
+pub fn space() -> Content {
+    SpaceElem::new().pack()
+}
+
+/// A break between paragraphs.
+pub fn parbreak() -> Content {
+    ParbreakElem::new().pack()
+}
+
+/// A break between pages.
+pub fn pagebreak() -> Content {
+    PagebreakElem::new().pack()
+}
+
+/// A heading of the given level. Levels start at one, zero is treated as one.
+pub fn heading(level: usize, body: Content) -> Content {
+    let level = NonZeroUsize::new(level).unwrap_or(NonZeroUsize::ONE);
+    HeadingElem::new(body).with_level(level).pack()
+}
+
+/// Strongly emphasized content.
+pub fn strong(body: Content) -> Content {
+    StrongElem::new(body).pack()
+}
+
+/// Emphasized content.
+pub fn emph(body: Content) -> Content {
+    EmphElem::new(body).pack()
+}
+
+/// A bullet list with the given items.
+pub fn list(items: impl IntoIterator<Item = Content>) -> Content {
+    ListElem::new(items.into_iter().map(ListItem::new).collect()).pack()
+}
+
+/// A numbered list with the given items.
+pub fn enumeration(items: impl IntoIterator<Item = Content>) -> Content {
+    EnumElem::new(items.into_iter().map(EnumItem::new).collect()).pack()
+}
+
+/// A table with the given number of auto-sized columns, filled row by row.
+pub fn table(columns: usize, cells: impl IntoIterator<Item = Content>) -> Content {
+    TableElem::new(cells.into_iter().collect())
+        .with_columns(TrackSizings(vec![Sizing::Auto; columns.max(1)]))
+        .pack()
+}
+
+/// An image decoded from raw data.
+///
+/// The name is only used to detect the format from its extension. Without a
+/// known extension, the format is detected from the data itself.
+pub fn image(name: impl Into<EcoString>, data: Bytes) -> Content {
+    ImageElem::new(name.into(), Readable::Bytes(data)).pack()
+}
+
+/// Lay out content on pages of the given width that grow with it.
+///
+/// The pages have no margins, so the content can be reflowed for containers
+/// of different widths without setting up page styles:
+///
+/// ```ignore
+/// let narrow = typst::compile_content(world, tracer, &at_width(body.clone(), Abs::pt(300.0)))?;
+/// let wide = typst::compile_content(world, tracer, &at_width(body, Abs::pt(500.0)))?;
+/// ```
+pub fn at_width(content: Content, width: Abs) -> Content {
+    let mut styles = Styles::new();
+    styles.set(PageElem::set_width(Smart::Custom(width.into())));
+    styles.set(PageElem::set_height(Smart::Auto));
+    styles.set(PageElem::set_margin(Margin::splat(Some(Smart::Custom(Rel::zero())))));
+    content.styled_with_map(styles)
+}
@@ -77,3 +77,15 @@ impl Show for AlignElem {
             .styled(Self::set_alignment(self.alignment(styles).map(Some))))
     }
 }
+
+/// Compute the position at which a frame must be placed in a region of the
+/// given size to honor the alignment of the surrounding content.
+pub fn align_frame(frame: &Frame, region: Size, styles: StyleChain) -> Point {
+    let alignment = AlignElem::alignment_in(styles)
+        .resolve(styles)
+        .unwrap_or(Axes::new(Align::Left, Align::Top));
+    Point::new(
+        alignment.x.position(region.x - frame.width()),
+        alignment.y.position(region.y - frame.height()),
+    )
+}
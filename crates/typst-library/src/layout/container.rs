@@ -1,8 +1,10 @@
 use typst::eval::AutoValue;
+use typst::font::VerticalFontMetric;
 
 use super::VElem;
 use crate::layout::Spacing;
 use crate::prelude::*;
+use crate::text::{families, variant};
 
 /// An inline-level container that sizes content.
 ///
@@ -42,13 +44,24 @@ pub struct BoxElem {
     /// The height of the box.
     pub height: Smart<Rel<Length>>,
 
-    /// An amount to shift the box's baseline by.
+    /// An amount to shift the box's baseline by or a vertical alignment
+    /// relative to the surrounding text.
+    ///
+    /// With an alignment, the box is positioned based on the metrics of the
+    /// current font:
+    /// - `{top}` aligns the box's top with the font's ascender.
+    /// - `{horizon}` centers the box at half the font's x-height.
+    /// - `{bottom}` aligns the box's bottom with the font's descender.
     ///
     /// ```example
     /// Image: #box(baseline: 40%, image("tiger.jpg", width: 2cm)).
+    ///
+    /// Top #box(baseline: top, square(size: 1.5em, fill: aqua)),
+    /// middle #box(baseline: horizon, square(size: 1.5em, fill: aqua)),
+    /// bottom #box(baseline: bottom, square(size: 1.5em, fill: aqua)).
     /// ```
-    #[resolve]
-    pub baseline: Rel<Length>,
+    #[default(BoxBaseline::Shift(Rel::zero()))]
+    pub baseline: BoxBaseline,
 
     /// The box's background color. See the
     /// [rectangle's documentation]($func/rect.fill) for more details.
@@ -139,7 +152,24 @@ impl Layout for BoxElem {
         *frame.size_mut() = expand.select(size, frame.size());
 
         // Apply baseline shift.
-        let shift = self.baseline(styles).relative_to(frame.height());
+        let shift = match self.baseline(styles) {
+            BoxBaseline::Shift(shift) => {
+                shift.resolve(styles).relative_to(frame.height())
+            }
+            BoxBaseline::Align(align) => {
+                // The distance between the frame's top and the baseline that
+                // yields the requested alignment.
+                let metric = |metric| font_metric(vt, styles, metric);
+                let target = match align.0.resolve(styles) {
+                    Align::Top => metric(VerticalFontMetric::Ascender),
+                    Align::Bottom => {
+                        frame.height() + metric(VerticalFontMetric::Descender)
+                    }
+                    _ => (frame.height() + metric(VerticalFontMetric::XHeight)) / 2.0,
+                };
+                frame.baseline() - target
+            }
+        };
         if !shift.is_zero() {
             frame.set_baseline(frame.baseline() - shift);
         }
@@ -167,6 +197,40 @@ impl Layout for BoxElem {
     }
 }
 
+/// How to vertically position a box relative to the surrounding text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BoxBaseline {
+    /// Shift the baseline by an amount.
+    Shift(Rel<Length>),
+    /// Align the box with the metrics of the current font.
+    Align(VerticalAlign),
+}
+
+cast! {
+    BoxBaseline,
+    self => match self {
+        Self::Shift(shift) => shift.into_value(),
+        Self::Align(align) => align.into_value(),
+    },
+    shift: Rel<Length> => Self::Shift(shift),
+    align: VerticalAlign => Self::Align(align),
+}
+
+/// Resolve a vertical metric of the first available font.
+///
+/// Returns zero if no font is available.
+fn font_metric(vt: &Vt, styles: StyleChain, metric: VerticalFontMetric) -> Abs {
+    let world = vt.world;
+    let variant = variant(styles);
+    families(styles)
+        .find_map(|family| {
+            let id = world.book().select(family.as_str(), variant)?;
+            world.font(id)
+        })
+        .map(|font| font.metrics().vertical(metric).resolve(styles))
+        .unwrap_or_default()
+}
+
 /// A block-level container.
 ///
 /// Such a container can be used to separate content, size it, and give it a
@@ -243,6 +307,23 @@ pub struct BlockElem {
     #[default(true)]
     pub breakable: bool,
 
+    /// How far unbreakable content may stick out of its region before a
+    /// warning is reported.
+    ///
+    /// Content that cannot be broken, like a shape, an image or a
+    /// non-breakable block, is placed even if it is wider or taller than the
+    /// region it ends up in. It then overflows into the margin. By default,
+    /// this is not reported. When set to a length, a warning is emitted for
+    /// such content if it overflows by more than this amount.
+    ///
+    /// ```example
+    /// #set page(width: 100pt, height: 80pt)
+    /// #set block(overflow-tolerance: 0pt)
+    /// #rect(width: 120pt, height: 40pt)
+    /// ```
+    #[default]
+    pub overflow_tolerance: Option<Length>,
+
     /// The block's background color. See the
     /// [rectangle's documentation]($func/rect.fill) for more details.
     pub fill: Option<Paint>,
@@ -320,6 +401,24 @@ pub struct BlockElem {
     #[default(false)]
     pub clip: bool,
 
+    /// Content in the block's background.
+    ///
+    /// The background is laid out after the body, with the block's final size
+    /// as its region, so it can be sized relative to the block. It is placed
+    /// behind the body and never affects the block's size. When the block
+    /// breaks across pages, each part gets its own background.
+    ///
+    /// ```example
+    /// #let rules = layout(size => {
+    ///   for y in range(1, int(size.height / 12pt) + 1) {
+    ///     place(dy: y * 12pt, line(length: 100%, stroke: 0.5pt + aqua))
+    ///   }
+    /// })
+    ///
+    /// #block(background: rules, lorem(20))
+    /// ```
+    pub background: Option<Content>,
+
     /// The contents of the block.
     #[positional]
     pub body: Option<Content>,
@@ -409,6 +508,22 @@ impl Layout for BlockElem {
             frames
         };
 
+        // Skip decorations for an empty first frame if the block continues
+        // in the next region.
+        let mut skip = false;
+        if let [first, rest @ ..] = frames.as_slice() {
+            skip = first.is_empty() && rest.iter().any(|frame| !frame.is_empty());
+        }
+
+        // Add the background behind the body.
+        if let Some(background) = self.background(styles) {
+            for frame in frames.iter_mut().skip(skip as usize) {
+                let pod = Regions::one(frame.size(), Axes::splat(true));
+                let layer = background.layout(vt, styles, pod)?.into_frame();
+                frame.prepend_frame(Point::zero(), layer);
+            }
+        }
+
         // Clip the contents
         if self.clip(styles) {
             for frame in frames.iter_mut() {
@@ -422,11 +537,6 @@ impl Layout for BlockElem {
 
         // Add fill and/or stroke.
         if fill.is_some() || stroke.iter().any(Option::is_some) {
-            let mut skip = false;
-            if let [first, rest @ ..] = frames.as_slice() {
-                skip = first.is_empty() && rest.iter().any(|frame| !frame.is_empty());
-            }
-
             let outset = self.outset(styles);
             let radius = self.radius(styles);
             for frame in frames.iter_mut().skip(skip as usize) {
@@ -56,7 +56,7 @@ impl Layout for FlowElem {
                 || child.is::<PathElem>()
             {
                 let layoutable = child.with::<dyn Layout>().unwrap();
-                layouter.layout_single(vt, layoutable, styles)?;
+                layouter.layout_single(vt, layoutable, child.span(), styles)?;
             } else if child.is::<MetaElem>() {
                 let mut frame = Frame::new(Size::zero());
                 frame.meta(styles, true);
@@ -256,12 +256,14 @@ impl<'a> FlowLayouter<'a> {
         &mut self,
         vt: &mut Vt,
         content: &dyn Layout,
+        span: Span,
         styles: StyleChain,
     ) -> SourceResult<()> {
         let aligns = AlignElem::alignment_in(styles).resolve(styles);
         let sticky = BlockElem::sticky_in(styles);
         let pod = Regions::one(self.regions.base(), Axes::splat(false));
         let frame = content.layout(vt, styles, pod)?.into_frame();
+        self.check_overflow(vt, &frame, span, styles);
         self.layout_item(vt, FlowItem::Frame { frame, aligns, sticky, movable: true })?;
         self.last_was_par = false;
         Ok(())
@@ -332,6 +334,7 @@ impl<'a> FlowLayouter<'a> {
                 self.finish_region(vt)?;
             }
 
+            self.check_overflow(vt, &frame, block.span(), styles);
             let item = FlowItem::Frame { frame, aligns, sticky, movable: false };
             self.layout_item(vt, item)?;
         }
@@ -345,6 +348,27 @@ impl<'a> FlowLayouter<'a> {
         Ok(())
     }
 
+    /// Report a frame that sticks out of the region by more than the
+    /// configured overflow tolerance.
+    fn check_overflow(&self, vt: &mut Vt, frame: &Frame, span: Span, styles: StyleChain) {
+        let Some(tolerance) = BlockElem::overflow_tolerance_in(styles) else { return };
+        if span.is_detached() {
+            return;
+        }
+
+        let tolerance = tolerance.resolve(styles);
+        let hint = if frame.width() - self.regions.size.x > tolerance {
+            eco_format!("it is {:?} too wide", frame.width() - self.regions.size.x)
+        } else if frame.height() - self.regions.full > tolerance {
+            eco_format!("it is {:?} too tall", frame.height() - self.regions.full)
+        } else {
+            return;
+        };
+
+        vt.tracer
+            .warn(warning!(span, "content overflows its region").with_hint(hint));
+    }
+
     /// Layout a finished frame.
     #[tracing::instrument(name = "FlowLayouter::layout_item", skip_all)]
     fn layout_item(&mut self, vt: &mut Vt, mut item: FlowItem) -> SourceResult<()> {
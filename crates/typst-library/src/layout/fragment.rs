@@ -39,6 +39,15 @@ impl Fragment {
         self.0
     }
 
+    /// Apply a function to each of the contained frames.
+    ///
+    /// This makes it easy to post-process the result of laying out a child,
+    /// e.g. to grow or decorate each of its frames.
+    pub fn map_frames(mut self, f: impl FnMut(&mut Frame)) -> Self {
+        self.0.iter_mut().for_each(f);
+        self
+    }
+
     /// Iterate over the contained frames.
     pub fn iter(&self) -> std::slice::Iter<Frame> {
         self.0.iter()
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::text::TextElem;
 
-use super::Sizing;
+use super::{Celled, Sizing};
 
 /// Arranges content in a grid.
 ///
@@ -62,6 +62,10 @@ use super::Sizing;
 /// Display: Grid
 /// Category: layout
 #[element(Layout)]
+#[scope(
+    scope.define("cell", GridCellElem::func());
+    scope
+)]
 pub struct GridElem {
     /// The column sizes.
     ///
@@ -94,6 +98,45 @@ pub struct GridElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// How many rows at the top of the grid form its header.
+    ///
+    /// When the grid breaks across pages or columns, the header rows are
+    /// repeated at the top of each continuation.
+    ///
+    /// ```example
+    /// #set page(height: 4cm)
+    /// #grid(
+    ///   columns: 2,
+    ///   header-rows: 1,
+    ///   row-gutter: 4pt,
+    ///   [*Name*], [*Score*],
+    ///   ..range(10).map(i => ([Player #i], [#(i * 7)])).flatten()
+    /// )
+    /// ```
+    #[default(0)]
+    pub header_rows: usize,
+
+    /// How to fill the cells. See the [table's
+    /// documentation]($func/table.fill) for more details.
+    pub fill: Celled<Option<Paint>>,
+
+    /// How to stroke the cells. See the [table's
+    /// documentation]($func/table.stroke) for more details.
+    ///
+    /// By default, grids have no lines.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 3,
+    ///   gutter: 4pt,
+    ///   stroke: (x, y) => (bottom: if y == 0 { 1pt }),
+    ///   [*A*], [*B*], [*C*],
+    ///   [1], [2], [3],
+    /// )
+    /// ```
+    #[fold]
+    pub stroke: Celled<Sides<Option<Option<PartialStroke>>>>,
+
     /// The contents of the grid cells.
     ///
     /// The cells are populated in row-major order.
@@ -117,11 +160,83 @@ impl Layout for GridElem {
             &cells,
             regions,
             styles,
-        );
+        )
+        .with_header(self.header_rows(styles));
 
         // Measure the columns and layout the grid row-by-row.
-        Ok(layouter.layout(vt)?.fragment)
+        let mut layout = layouter.layout(vt)?;
+        layout.decorate(
+            vt,
+            styles,
+            &self.fill(styles),
+            &self.stroke(styles),
+            self.span(),
+        )?;
+        Ok(layout.fragment)
+    }
+}
+
+/// A cell in a grid or table that can span multiple columns.
+///
+/// Cells are usually given as plain content. Wrapping one into this function
+/// allows to configure how it is placed.
+///
+/// ## Example { #example }
+/// ```example
+/// #table(
+///   columns: 3,
+///   [Name], table.cell(colspan: 2)[Score],
+///   [], [Day 1], [Day 2],
+///   [Anna], [3], [5],
+/// )
+/// ```
+///
+/// Display: Grid Cell
+/// Category: layout
+#[element(Show)]
+pub struct GridCellElem {
+    /// How many columns the cell spans.
+    ///
+    /// A cell never spans past the end of its row: If fewer columns remain,
+    /// it spans only those. If the cell's content doesn't fit into the
+    /// columns it spans, the automatically sized ones among them grow.
+    #[default(NonZeroUsize::ONE)]
+    pub colspan: NonZeroUsize,
+
+    /// The cell's content.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for GridCellElem {
+    fn show(&self, _: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+        Ok(self.body())
+    }
+}
+
+/// Place cells into a grid with the given number of columns, row by row.
+///
+/// Returns the column, row and column span of each cell.
+pub fn place_cells(
+    cells: &[Content],
+    cols: usize,
+    styles: StyleChain,
+) -> Vec<(usize, usize, usize)> {
+    let mut placed = Vec::with_capacity(cells.len());
+    let (mut x, mut y) = (0, 0);
+    for cell in cells {
+        let span = cell
+            .to::<GridCellElem>()
+            .map_or(1, |cell| cell.colspan(styles).get())
+            .min(cols - x);
+        placed.push((x, y, span));
+        x += span;
+        if x >= cols {
+            x = 0;
+            y += 1;
+        }
     }
+    placed
 }
 
 /// Track sizing definitions.
@@ -140,8 +255,6 @@ cast! {
 pub struct GridLayouter<'a> {
     /// The grid cells.
     cells: &'a [Content],
-    /// Whether this is an RTL grid.
-    is_rtl: bool,
     /// Whether this grid has gutters.
     has_gutter: bool,
     /// The column tracks including gutter tracks.
@@ -164,6 +277,20 @@ pub struct GridLayouter<'a> {
     initial: Size,
     /// Frames for finished regions.
     finished: Vec<Frame>,
+    /// The number of rows, including gutter rows, that form the header.
+    header: usize,
+    /// The height of the header when it was first laid out.
+    header_height: Abs,
+    /// Whether the header is repeated when a new region begins.
+    repeat: bool,
+    /// For each track position, the index of the cell that starts there and
+    /// the number of tracks it spans.
+    placement: Vec<Option<(usize, usize)>>,
+    /// For each track position, whether it continues a cell that starts
+    /// further left.
+    merged: Vec<bool>,
+    /// For each track position where a cell starts, the cell's column and row.
+    origins: Vec<Option<(usize, usize)>>,
 }
 
 /// The resulting sizes of columns and rows in a grid.
@@ -175,6 +302,251 @@ pub struct GridLayout {
     pub cols: Vec<Abs>,
     /// The heights of the resulting rows segments, by region.
     pub rows: Vec<Vec<RowPiece>>,
+    /// For each track position, whether it continues a cell that starts
+    /// further left.
+    merged: Vec<bool>,
+    /// For each track position where a cell starts, the cell's column and row.
+    origins: Vec<Option<(usize, usize)>>,
+}
+
+impl GridLayout {
+    /// Add lines and cell backgrounds to the laid out frames.
+    ///
+    /// The fill and stroke functions receive the cells' column and row, not
+    /// the indices of the tracks, which also count gutters. If all cells are
+    /// stroked uniformly, the lines run across the whole grid. Otherwise, each
+    /// cell strokes its own sides and an edge shared by two adjacent cells is
+    /// drawn only once.
+    pub fn decorate(
+        &mut self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        fill: &Celled<Option<Paint>>,
+        stroke: &Celled<Sides<Option<Option<PartialStroke>>>>,
+        span: Span,
+    ) -> SourceResult<()> {
+        let resolve = |sides: Sides<Option<Option<PartialStroke>>>| {
+            sides
+                .map(|side| side.flatten().map(|s| s.resolve(styles).unwrap_or_default()))
+        };
+
+        let uniform = match stroke {
+            Celled::Value(sides) if sides.is_uniform() => {
+                Some(resolve(sides.clone()).left)
+            }
+            _ => None,
+        };
+
+        let ncols = self.cols.len();
+        for (frame, rows) in self.fragment.iter_mut().zip(&self.rows) {
+            if ncols == 0 || rows.is_empty() {
+                continue;
+            }
+
+            // Find the cells in this region and the track positions they
+            // cover and resolve their fills and strokes.
+            let mut fills = vec![];
+            let mut cells = vec![];
+            let mut owners = vec![None; rows.len() * ncols];
+            let mut dy = Abs::zero();
+            for (i, row) in rows.iter().enumerate() {
+                let mut dx = Abs::zero();
+                for tx in 0..ncols {
+                    if let Some((x, y)) = self.origins[row.y * ncols + tx] {
+                        let end = (tx + 1..ncols)
+                            .find(|&k| !self.merged[row.y * ncols + k])
+                            .unwrap_or(ncols);
+                        let size = Size::new(self.cols[tx..end].iter().sum(), row.height);
+                        if let Some(fill) = fill.resolve(vt, x, y)? {
+                            fills.push((Point::new(dx, dy), size, fill));
+                        }
+                        if uniform.is_none() {
+                            owners[i * ncols + tx..i * ncols + end]
+                                .fill(Some(cells.len()));
+                            cells.push(resolve(stroke.resolve(vt, x, y)?));
+                        }
+                    }
+                    dx += self.cols[tx];
+                }
+                dy += row.height;
+            }
+
+            match &uniform {
+                Some(Some(stroke)) => {
+                    stroke_lines(frame, &self.cols, rows, &self.merged, stroke, span)
+                }
+                Some(None) => {}
+                None => stroke_edges(frame, &self.cols, rows, &owners, &cells, span),
+            }
+
+            // Render cell backgrounds.
+            for (pos, size, fill) in fills {
+                let rect = Geometry::Rect(size).filled(fill);
+                frame.prepend(pos, FrameItem::Shape(rect, span));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the boundary before column `x` in row `y` lies within a cell
+    /// that spans multiple columns.
+    pub fn is_merged(&self, x: usize, y: usize) -> bool {
+        is_merged(&self.merged, self.cols.len(), x, y)
+    }
+}
+
+/// Whether the boundary before column `x` in row `y` lies within a cell that
+/// spans multiple columns.
+fn is_merged(merged: &[bool], cols: usize, x: usize, y: usize) -> bool {
+    x < cols && merged[y * cols + x]
+}
+
+/// Stroke lines across the whole grid. Vertical lines are interrupted within
+/// cells that span multiple columns.
+fn stroke_lines(
+    frame: &mut Frame,
+    cols: &[Abs],
+    rows: &[RowPiece],
+    merged: &[bool],
+    stroke: &Stroke,
+    span: Span,
+) {
+    let thickness = stroke.thickness;
+    let half = thickness / 2.0;
+
+    // Render horizontal lines.
+    for offset in points(rows.iter().map(|piece| piece.height)) {
+        let target = Point::with_x(frame.width() + thickness);
+        let hline = Geometry::Line(target).stroked(stroke.clone());
+        frame.prepend(Point::new(-half, offset), FrameItem::Shape(hline, span));
+    }
+
+    // Render vertical lines.
+    for (x, offset) in points(cols.iter().copied()).enumerate() {
+        if !rows.iter().any(|row| is_merged(merged, cols.len(), x, row.y)) {
+            let target = Point::with_y(frame.height() + thickness);
+            let vline = Geometry::Line(target).stroked(stroke.clone());
+            frame.prepend(Point::new(offset, -half), FrameItem::Shape(vline, span));
+            continue;
+        }
+
+        let mut dy = Abs::zero();
+        for row in rows {
+            if !is_merged(merged, cols.len(), x, row.y) {
+                let target = Point::with_y(row.height + thickness);
+                let vline = Geometry::Line(target).stroked(stroke.clone());
+                frame.prepend(
+                    Point::new(offset, dy - half),
+                    FrameItem::Shape(vline, span),
+                );
+            }
+            dy += row.height;
+        }
+    }
+}
+
+/// Stroke the sides of individually stroked cells.
+///
+/// The owners map each track position of the region to the cell covering it.
+/// Where two cells share an edge, it is drawn once with the stroke of the cell
+/// below or to the right, or, if that one has none, with the other cell's.
+fn stroke_edges(
+    frame: &mut Frame,
+    cols: &[Abs],
+    rows: &[RowPiece],
+    owners: &[Option<usize>],
+    cells: &[Sides<Option<Stroke>>],
+    span: Span,
+) {
+    let ncols = cols.len();
+    let owner = |i: usize, tx: usize| owners[i * ncols + tx];
+    let pick = |after: Option<&Option<Stroke>>, before: Option<&Option<Stroke>>| {
+        after.cloned().flatten().or_else(|| before.cloned().flatten())
+    };
+
+    // Render horizontal edges, between the rows above and below each offset.
+    for (i, y) in points(rows.iter().map(|row| row.height)).enumerate() {
+        let edges = (0..ncols).map(|tx| {
+            let above = i.checked_sub(1).and_then(|k| owner(k, tx));
+            let below = if i < rows.len() { owner(i, tx) } else { None };
+            ((above, below), cols[tx])
+        });
+
+        let mut x = Abs::zero();
+        for ((above, below), length) in runs(edges) {
+            let top = below.map(|c| &cells[c].top);
+            let bottom = above.map(|c| &cells[c].bottom);
+            if let Some(stroke) = pick(top, bottom) {
+                stroke_edge(frame, Point::new(x, y), length, Axis::X, stroke, span);
+            }
+            x += length;
+        }
+    }
+
+    // Render vertical edges, between the columns left and right of each
+    // offset. Edges within a cell spanning multiple columns are skipped.
+    for (tx, x) in points(cols.iter().copied()).enumerate() {
+        let edges = rows.iter().enumerate().map(|(i, row)| {
+            let left = tx.checked_sub(1).and_then(|k| owner(i, k));
+            let right = if tx < ncols { owner(i, tx) } else { None };
+            let key = if left == right { (None, None) } else { (left, right) };
+            (key, row.height)
+        });
+
+        let mut y = Abs::zero();
+        for ((left, right), length) in runs(edges) {
+            let start = right.map(|c| &cells[c].left);
+            let end = left.map(|c| &cells[c].right);
+            if let Some(stroke) = pick(start, end) {
+                stroke_edge(frame, Point::new(x, y), length, Axis::Y, stroke, span);
+            }
+            y += length;
+        }
+    }
+}
+
+/// Merge consecutive extents with equal keys.
+fn runs<K: PartialEq>(extents: impl IntoIterator<Item = (K, Abs)>) -> Vec<(K, Abs)> {
+    let mut runs: Vec<(K, Abs)> = vec![];
+    for (key, extent) in extents {
+        match runs.last_mut() {
+            Some((last, total)) if *last == key => *total += extent,
+            _ => runs.push((key, extent)),
+        }
+    }
+    runs
+}
+
+/// Stroke a single edge along the given axis, extended by half the stroke's
+/// thickness on both ends so that edges meet at the corners.
+fn stroke_edge(
+    frame: &mut Frame,
+    pos: Point,
+    length: Abs,
+    axis: Axis,
+    stroke: Stroke,
+    span: Span,
+) {
+    let thickness = stroke.thickness;
+    let (start, target) = match axis {
+        Axis::X => (Point::with_x(thickness / 2.0), Point::with_x(length + thickness)),
+        Axis::Y => (Point::with_y(thickness / 2.0), Point::with_y(length + thickness)),
+    };
+    let line = Geometry::Line(target).stroked(stroke);
+    frame.prepend(pos - start, FrameItem::Shape(line, span));
+}
+
+/// Turn an iterator of extents into an iterator of offsets before, in between,
+/// and after the extents, e.g. [10mm, 5mm] -> [0mm, 10mm, 15mm].
+fn points(extents: impl IntoIterator<Item = Abs>) -> impl Iterator<Item = Abs> {
+    let mut offset = Abs::zero();
+    std::iter::once(Abs::zero())
+        .chain(extents.into_iter())
+        .map(move |extent| {
+            offset += extent;
+            offset
+        })
 }
 
 /// Details about a resulting row piece.
@@ -214,10 +586,10 @@ impl<'a> GridLayouter<'a> {
 
         // Number of content rows: At least as many as given, but also at least
         // as many as needed to place each item.
+        let placed = place_cells(cells, c, styles);
         let r = {
-            let len = cells.len();
             let given = tracks.y.len();
-            let needed = len / c + (len % c).clamp(0, 1);
+            let needed = placed.last().map_or(0, |&(_, y, _)| y + 1);
             given.max(needed)
         };
 
@@ -256,6 +628,37 @@ impl<'a> GridLayouter<'a> {
             cols.reverse();
         }
 
+        // Determine the column and row of the cell at each track position.
+        // Gutter tracks have none.
+        let mut origins = vec![None; cols.len() * rows.len()];
+        for ty in (0..rows.len()).filter(|ty| !has_gutter || ty % 2 == 0) {
+            for tx in 0..cols.len() {
+                let x = if is_rtl { cols.len() - 1 - tx } else { tx };
+                if !has_gutter || x % 2 == 0 {
+                    let cell = if has_gutter { (x / 2, ty / 2) } else { (x, ty) };
+                    origins[ty * cols.len() + tx] = Some(cell);
+                }
+            }
+        }
+
+        // Map the cells to the tracks they start at.
+        let mut placement = vec![None; cols.len() * rows.len()];
+        let mut merged = vec![false; cols.len() * rows.len()];
+        let track = |i: usize| if has_gutter { 2 * i } else { i };
+        for (i, &(x, y, span)) in placed.iter().enumerate() {
+            let span = if has_gutter { 2 * span - 1 } else { span };
+            let start = if is_rtl { cols.len() - track(x) - span } else { track(x) };
+            let row = track(y) * cols.len();
+            placement[row + start] = Some((i, span));
+            origins[row + start] = Some((x, y));
+            for covered in &mut merged[row + start + 1..row + start + span] {
+                *covered = true;
+            }
+            for covered in &mut origins[row + start + 1..row + start + span] {
+                *covered = None;
+            }
+        }
+
         // We use these regions for auto row measurement. Since at that moment,
         // columns are already sized, we can enable horizontal expansion.
         let mut regions = regions;
@@ -263,7 +666,6 @@ impl<'a> GridLayouter<'a> {
 
         Self {
             cells,
-            is_rtl,
             has_gutter,
             rows,
             regions,
@@ -275,9 +677,23 @@ impl<'a> GridLayouter<'a> {
             lrows: vec![],
             initial: regions.size,
             finished: vec![],
+            header: 0,
+            header_height: Abs::zero(),
+            repeat: false,
+            placement,
+            merged,
+            origins,
         }
     }
 
+    /// Repeat the given number of rows at the top of each region after the
+    /// first one.
+    pub fn with_header(mut self, rows: usize) -> Self {
+        let rows = if self.has_gutter { 2 * rows } else { rows };
+        self.header = rows.min(self.rows.len());
+        self
+    }
+
     /// Determines the columns sizes and then layouts the grid row-by-row.
     pub fn layout(mut self, vt: &mut Vt) -> SourceResult<GridLayout> {
         self.measure_columns(vt)?;
@@ -294,14 +710,23 @@ impl<'a> GridLayouter<'a> {
                 Sizing::Rel(v) => self.layout_relative_row(vt, v, y)?,
                 Sizing::Fr(v) => self.lrows.push(Row::Fr(v, y)),
             }
+
+            // Once the header is complete, repeat it in all further regions.
+            if y + 1 == self.header && self.header < self.rows.len() {
+                self.header_height = self.header_height();
+                self.repeat = true;
+            }
         }
 
+        self.repeat = false;
         self.finish_region(vt)?;
 
         Ok(GridLayout {
             fragment: Fragment::frames(self.finished),
             cols: self.rcols,
             rows: self.rrows,
+            merged: self.merged,
+            origins: self.origins,
         })
     }
 
@@ -369,20 +794,14 @@ impl<'a> GridLayouter<'a> {
 
             let mut resolved = Abs::zero();
             for y in 0..self.rows.len() {
-                if let Some(cell) = self.cell(x, y) {
-                    // For relative rows, we can already resolve the correct
-                    // base and for auto and fr we could only guess anyway.
-                    let height = match self.rows[y] {
-                        Sizing::Rel(v) => {
-                            v.resolve(self.styles).relative_to(self.regions.base().y)
-                        }
-                        _ => self.regions.base().y,
-                    };
+                // Cells that span multiple columns are handled below.
+                if self.span(x, y) > 1 {
+                    continue;
+                }
 
-                    let size = Size::new(available, height);
-                    let pod = Regions::one(size, Axes::splat(false));
-                    let frame = cell.measure(vt, self.styles, pod)?.into_frame();
-                    resolved.set_max(frame.width());
+                if let Some(cell) = self.cell(x, y) {
+                    let width = self.measure_cell_width(vt, cell, y, available)?;
+                    resolved.set_max(width);
                 }
             }
 
@@ -391,9 +810,63 @@ impl<'a> GridLayouter<'a> {
             count += 1;
         }
 
+        // A cell spanning multiple columns that doesn't fit into them grows
+        // the auto columns it spans, sharing the missing width equally. If it
+        // spans a fractional column, that one takes up the rest instead.
+        for y in 0..self.rows.len() {
+            for x in 0..self.cols.len() {
+                let span = self.span(x, y);
+                if span == 1 {
+                    continue;
+                }
+
+                let spanned = &self.cols[x..x + span];
+                let autos = spanned.iter().filter(|&&col| col == Sizing::Auto).count();
+                if autos == 0 || spanned.iter().any(|col| matches!(col, Sizing::Fr(_))) {
+                    continue;
+                }
+
+                let Some(cell) = self.cell(x, y) else { continue };
+                let width = self.measure_cell_width(vt, cell, y, available)?;
+                let missing = width - self.cell_width(x, y);
+                if missing <= Abs::zero() {
+                    continue;
+                }
+
+                let share = missing / autos as f64;
+                for (col, rcol) in spanned.iter().zip(&mut self.rcols[x..x + span]) {
+                    if *col == Sizing::Auto {
+                        *rcol += share;
+                    }
+                }
+                auto += missing;
+            }
+        }
+
         Ok((auto, count))
     }
 
+    /// Measure the natural width of a cell in row `y`.
+    fn measure_cell_width(
+        &self,
+        vt: &mut Vt,
+        cell: &Content,
+        y: usize,
+        available: Abs,
+    ) -> SourceResult<Abs> {
+        // For relative rows, we can already resolve the correct base and for
+        // auto and fr we could only guess anyway.
+        let height = match self.rows[y] {
+            Sizing::Rel(v) => v.resolve(self.styles).relative_to(self.regions.base().y),
+            _ => self.regions.base().y,
+        };
+
+        let size = Size::new(available, height);
+        let pod = Regions::one(size, Axes::splat(false));
+        let frame = cell.measure(vt, self.styles, pod)?.into_frame();
+        Ok(frame.width())
+    }
+
     /// Distribute remaining space to fractional columns.
     fn grow_fractional_columns(&mut self, remaining: Abs, fr: Fr) {
         if fr.is_zero() {
@@ -468,8 +941,9 @@ impl<'a> GridLayouter<'a> {
         // Expand all but the last region.
         // Skip the first region if the space is eaten up by an fr row.
         let len = resolved.len();
+        let mut backlog = vec![];
         for (region, target) in self
-            .regions
+            .row_regions(&mut backlog)
             .iter()
             .zip(&mut resolved[..len - 1])
             .skip(self.lrows.iter().any(|row| matches!(row, Row::Fr(..))) as usize)
@@ -499,11 +973,13 @@ impl<'a> GridLayouter<'a> {
         can_skip: bool,
     ) -> SourceResult<Option<Vec<Abs>>> {
         let mut resolved: Vec<Abs> = vec![];
+        let mut backlog = vec![];
+        let regions = self.row_regions(&mut backlog);
 
-        for (x, &rcol) in self.rcols.iter().enumerate() {
+        for x in 0..self.rcols.len() {
             if let Some(cell) = self.cell(x, y) {
-                let mut pod = self.regions;
-                pod.size.x = rcol;
+                let mut pod = regions;
+                pod.size.x = self.cell_width(x, y);
 
                 let frames = cell.measure(vt, self.styles, pod)?.into_frames();
 
@@ -571,7 +1047,7 @@ impl<'a> GridLayouter<'a> {
 
         for (x, &rcol) in self.rcols.iter().enumerate() {
             if let Some(cell) = self.cell(x, y) {
-                let size = Size::new(rcol, height);
+                let size = Size::new(self.cell_width(x, y), height);
                 let mut pod = Regions::one(size, Axes::splat(true));
                 if self.rows[y] == Sizing::Auto {
                     pod.full = self.regions.full;
@@ -609,7 +1085,7 @@ impl<'a> GridLayouter<'a> {
         let mut pos = Point::zero();
         for (x, &rcol) in self.rcols.iter().enumerate() {
             if let Some(cell) = self.cell(x, y) {
-                pod.size.x = rcol;
+                pod.size.x = self.cell_width(x, y);
 
                 // Push the layouted frames into the individual output frames.
                 let fragment = cell.layout(vt, self.styles, pod)?;
@@ -676,33 +1152,92 @@ impl<'a> GridLayouter<'a> {
         self.regions.next();
         self.initial = self.regions.size;
 
+        if self.repeat {
+            self.layout_header(vt)?;
+        }
+
         Ok(())
     }
 
-    /// Get the content of the cell in column `x` and row `y`.
+    /// Layout the header rows again at the top of the current region.
+    fn layout_header(&mut self, vt: &mut Vt) -> SourceResult<()> {
+        for y in 0..self.header {
+            let height = match self.rows[y] {
+                Sizing::Auto => {
+                    let mut height = Abs::zero();
+                    for x in 0..self.rcols.len() {
+                        if let Some(cell) = self.cell(x, y) {
+                            let size = Size::new(self.cell_width(x, y), Abs::inf());
+                            let pod = Regions::one(size, Axes::new(true, false));
+                            let frame = cell.measure(vt, self.styles, pod)?.into_frame();
+                            height.set_max(frame.height());
+                        }
+                    }
+                    height
+                }
+                Sizing::Rel(v) => {
+                    v.resolve(self.styles).relative_to(self.regions.base().y)
+                }
+                Sizing::Fr(v) => {
+                    self.lrows.push(Row::Fr(v, y));
+                    continue;
+                }
+            };
+
+            let frame = self.layout_single_row(vt, height, y)?;
+            self.push_row(frame, y);
+        }
+
+        Ok(())
+    }
+
+    /// The total height of the header rows laid out in the current region.
+    fn header_height(&self) -> Abs {
+        self.lrows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Frame(frame, y) if *y < self.header => Some(frame.height()),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// The regions available to a row, where the followup regions are
+    /// reduced by the height of a repeated header.
+    fn row_regions<'b>(&self, backlog: &'b mut Vec<Abs>) -> Regions<'b>
+    where
+        'a: 'b,
+    {
+        let mut regions = self.regions;
+        if self.repeat {
+            let header = self.header_height;
+            backlog.extend(self.regions.backlog.iter().map(|&height| height - header));
+            regions.backlog = backlog.as_slice();
+            regions.last = regions.last.map(|height| height - header);
+        }
+        regions
+    }
+
+    /// Get the content of the cell that starts in column `x` and row `y`.
     ///
-    /// Returns `None` if it's a gutter cell.
+    /// Returns `None` if it's a gutter cell or covered by a cell that spans
+    /// multiple columns.
     #[track_caller]
-    fn cell(&self, mut x: usize, y: usize) -> Option<&'a Content> {
+    fn cell(&self, x: usize, y: usize) -> Option<&'a Content> {
         assert!(x < self.cols.len());
         assert!(y < self.rows.len());
+        let (i, _) = self.placement[y * self.cols.len() + x]?;
+        self.cells.get(i)
+    }
 
-        // Columns are reorder, but the cell slice is not.
-        if self.is_rtl {
-            x = self.cols.len() - 1 - x;
-        }
+    /// The number of tracks spanned by the cell that starts in column `x`
+    /// and row `y`.
+    fn span(&self, x: usize, y: usize) -> usize {
+        self.placement[y * self.cols.len() + x].map_or(1, |(_, span)| span)
+    }
 
-        if self.has_gutter {
-            // Even columns and rows are children, odd ones are gutter.
-            if x % 2 == 0 && y % 2 == 0 {
-                let c = 1 + self.cols.len() / 2;
-                self.cells.get((y / 2) * c + x / 2)
-            } else {
-                None
-            }
-        } else {
-            let c = self.cols.len();
-            self.cells.get(y * c + x)
-        }
+    /// The width of the cell that starts in column `x` and row `y`.
+    fn cell_width(&self, x: usize, y: usize) -> Abs {
+        self.rcols[x..x + self.span(x, y)].iter().sum()
     }
 }
@@ -34,6 +34,14 @@ use super::GridLayouter;
 /// paragraphs and other block-level content. All content that is indented
 /// more than an item's marker becomes part of that item.
 ///
+/// To turn an item into a task, follow the hyphen with a checkbox: `[ ]` for
+/// an open task and `[x]` for a completed one.
+///
+/// ```example
+/// - [x] Write the draft
+/// - [ ] Proofread
+/// ```
+///
 /// Display: Bullet List
 /// Category: layout
 #[element(Layout)]
@@ -85,6 +93,21 @@ pub struct ListElem {
     #[default(ListMarker::Content(vec![TextElem::packed('•')]))]
     pub marker: ListMarker,
 
+    /// The markers which introduce task items, for open and completed tasks
+    /// respectively. These replace the regular [marker]($func/list.marker)
+    /// for items that are [checked]($func/list.item.checked) or not.
+    ///
+    /// ```example
+    /// #set list(checkbox: ([○], [●]))
+    /// - [ ] Open
+    /// - [x] Done
+    /// ```
+    #[default(Checkbox {
+        open: TextElem::packed('☐'),
+        done: TextElem::packed('☑'),
+    })]
+    pub checkbox: Checkbox,
+
     /// The indent of each item.
     #[resolve]
     pub indent: Length,
@@ -142,10 +165,18 @@ impl Layout for ListElem {
             // avoid '#set align' interference with the list
             .aligned(Align::LEFT_TOP.into());
 
+        let checkbox = self.checkbox(styles);
         let mut cells = vec![];
         for item in self.children() {
+            let marker = match item.checked(styles) {
+                Some(checked) => {
+                    checkbox.get(checked).clone().aligned(Align::LEFT_TOP.into())
+                }
+                None => marker.clone(),
+            };
+
             cells.push(Content::empty());
-            cells.push(marker.clone());
+            cells.push(marker);
             cells.push(Content::empty());
             cells.push(item.body().styled(Self::set_depth(Depth)));
         }
@@ -173,6 +204,17 @@ impl Layout for ListElem {
 /// Category: layout
 #[element]
 pub struct ListItem {
+    /// Whether the item is a task and, if so, whether it is completed.
+    ///
+    /// ```example
+    /// #list(
+    ///   list.item(checked: true)[Done],
+    ///   list.item(checked: false)[Open],
+    ///   [No task],
+    /// )
+    /// ```
+    pub checked: Option<bool>,
+
     /// The item's body.
     #[required]
     pub body: Content,
@@ -222,6 +264,41 @@ cast! {
     v: Func => Self::Func(v),
 }
 
+/// The markers of open and completed tasks.
+#[derive(Debug, Clone, Hash)]
+pub struct Checkbox {
+    /// The marker of an open task.
+    pub open: Content,
+    /// The marker of a completed task.
+    pub done: Content,
+}
+
+impl Checkbox {
+    /// The marker for a task with the given state.
+    fn get(&self, checked: bool) -> &Content {
+        if checked {
+            &self.done
+        } else {
+            &self.open
+        }
+    }
+}
+
+cast! {
+    Checkbox,
+    self => array![self.open, self.done].into_value(),
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(open), Some(done), None) => Self {
+                open: open.display(),
+                done: done.display(),
+            },
+            _ => bail!("array must contain exactly two markers"),
+        }
+    },
+}
+
 struct Depth;
 
 cast! {
@@ -45,6 +45,25 @@ pub fn measure(
     content: Content,
     /// The styles with which to layout the content.
     styles: Styles,
+    /// Whether to also measure the baseline and the drawn bounds.
+    ///
+    /// If this is `{true}`, the dictionary additionally has the entry
+    /// `baseline`, the distance from the top to the content's baseline, and
+    /// the entry `ink`, the bounds of what is actually drawn, with glyphs
+    /// measured by their outlines instead of their advance and the font's
+    /// ascender and descender. The ink bounds are a dictionary with `x` and
+    /// `y` relative to the top-left corner, `width` and `height`, or `{none}`
+    /// if nothing is drawn.
+    ///
+    /// ```example
+    /// #style(styles => {
+    ///   let m = measure([x], styles, bounds: true)
+    ///   [Ink height of x: #m.ink.height]
+    /// })
+    /// ```
+    #[named]
+    #[default(false)]
+    bounds: bool,
     /// The virtual machine.
     vm: &mut Vm,
 ) -> SourceResult<Dict> {
@@ -52,5 +71,22 @@ pub fn measure(
     let styles = StyleChain::new(&styles);
     let frame = content.measure(&mut vm.vt, styles, pod)?.into_frame();
     let Size { x, y } = frame.size();
-    Ok(dict! { "width" => x, "height" => y })
+    let mut dict = dict! { "width" => x, "height" => y };
+    if !bounds {
+        return Ok(dict);
+    }
+
+    let ink = match frame.ink_bbox() {
+        Some((pos, size)) => dict! {
+            "x" => pos.x,
+            "y" => pos.y,
+            "width" => size.x,
+            "height" => size.y,
+        }
+        .into_value(),
+        None => Value::None,
+    };
+    dict.insert("baseline".into(), frame.baseline().into_value());
+    dict.insert("ink".into(), ink);
+    Ok(dict)
 }
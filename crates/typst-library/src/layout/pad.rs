@@ -1,10 +1,15 @@
 use crate::prelude::*;
 
+use super::PageElem;
+
 /// Adds spacing around content.
 ///
 /// The spacing can be specified for each side individually, or for all sides at
 /// once by specifying a positional argument.
 ///
+/// Padding can also be negative. Then, the content bleeds out of the padded
+/// box, overlapping whatever surrounds it.
+///
 /// ## Example { #example }
 /// ```example
 /// #set align(center)
@@ -53,6 +58,23 @@ pub struct PadElem {
     #[external]
     pub rest: Rel<Length>,
 
+    /// What relative padding is resolved against.
+    ///
+    /// By default, percentages are relative to the padded box itself. When set
+    /// to `{"page"}`, they are relative to the page's width (for `left` and
+    /// `right`) or height (for `top` and `bottom`) instead, so that padding is
+    /// the same no matter how deeply it is nested. Along an axis where the
+    /// page's size is `{auto}`, padding stays relative to the box.
+    ///
+    /// ```example
+    /// #set page(width: 120pt)
+    /// #pad(x: 10%, relative: "page", rect(width: 100%)[
+    ///   #pad(x: 10%, relative: "page", rect(width: 100%))
+    /// ])
+    /// ```
+    #[default(PadRelative::Itself)]
+    pub relative: PadRelative,
+
     /// The content to pad at the sides.
     #[required]
     pub body: Content,
@@ -75,11 +97,27 @@ impl Layout for PadElem {
 
         // Layout child into padded regions.
         let mut backlog = vec![];
-        let padding = sides.resolve(styles);
+        let mut padding = sides.resolve(styles);
+        if self.relative(styles) == PadRelative::Page {
+            padding = relative_to_page(padding, styles);
+        }
+
+        // With relative padding of 100% or more along an axis, no size is
+        // left for the body and `grow` would divide by zero or produce a
+        // negative size.
+        let sum = padding.sum_by_axis();
+        for (axis, total) in [("horizontal", sum.x.rel), ("vertical", sum.y.rel)] {
+            if total.get() >= 1.0 {
+                return Err("relative padding must add up to less than 100%")
+                    .hint(eco_format!("the {axis} padding adds up to {total:?}"))
+                    .at(self.span());
+            }
+        }
+
         let pod = regions.map(&mut backlog, |size| shrink(size, padding));
-        let mut fragment = self.body().layout(vt, styles, pod)?;
+        let fragment = self.body().layout(vt, styles, pod)?;
 
-        for frame in &mut fragment {
+        Ok(fragment.map_frames(|frame| {
             // Apply the padding inversely such that the grown size padded
             // yields the frame's size.
             let padded = grow(frame.size(), padding);
@@ -89,10 +127,46 @@ impl Layout for PadElem {
             // Grow the frame and translate everything in the frame inwards.
             frame.set_size(padded);
             frame.translate(offset);
-        }
+        }))
+    }
+}
+
+/// What relative padding is resolved against.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum PadRelative {
+    /// Relative to the padded box itself.
+    #[string("self")]
+    Itself,
+    /// Relative to the size of the page.
+    Page,
+}
 
-        Ok(fragment)
+/// Turn padding relative to the page into absolute padding.
+///
+/// Sides whose page extent is `auto` keep their relative part.
+fn relative_to_page(padding: Sides<Rel<Abs>>, styles: StyleChain) -> Sides<Rel<Abs>> {
+    let mut page = Size::new(
+        PageElem::width_in(styles).unwrap_or(Abs::inf()),
+        PageElem::height_in(styles).unwrap_or(Abs::inf()),
+    );
+    if PageElem::flipped_in(styles) {
+        std::mem::swap(&mut page.x, &mut page.y);
     }
+
+    let absolute = |rel: Rel<Abs>, extent: Abs| {
+        if extent.is_finite() {
+            Rel::from(rel.relative_to(extent))
+        } else {
+            rel
+        }
+    };
+
+    Sides::new(
+        absolute(padding.left, page.x),
+        absolute(padding.top, page.y),
+        absolute(padding.right, page.x),
+        absolute(padding.bottom, page.y),
+    )
 }
 
 /// Shrink a size by padding relative to the size itself.
@@ -119,7 +193,10 @@ fn shrink(size: Size, padding: Sides<Rel<Abs>>) -> Size {
 ///   <=> w - p.rel * w - p.abs = s
 ///   <=> (1 - p.rel) * w = s + p.abs
 ///   <=> w = (s + p.abs) / (1 - p.rel)
+///
+/// Negative padding can make the grown size negative if it exceeds the given
+/// size, so the result is clamped to zero.
 fn grow(size: Size, padding: Sides<Rel<Abs>>) -> Size {
     size.zip(padding.sum_by_axis())
-        .map(|(s, p)| (s + p.abs).safe_div(1.0 - p.rel.get()))
+        .map(|(s, p)| (s + p.abs).safe_div(1.0 - p.rel.get()).max(Abs::zero()))
 }
@@ -14,6 +14,9 @@ use crate::text::TextElem;
 ///
 /// Pages can be set to use `{auto}` as their width or height. In this case,
 /// the pages will grow to fit their content on the respective axis.
+/// Combined with a zero margin, this yields pages that are exactly as large as
+/// their content, which is useful to export snippets:
+/// `{set page(width: auto, height: auto, margin: 0pt)}`.
 ///
 /// ## Example { #example }
 /// ```example
@@ -138,6 +141,27 @@ pub struct PageElem {
     /// margins.
     pub binding: Smart<Binding>,
 
+    /// Extra space to reserve at the bound side of each page.
+    ///
+    /// This binding offset is added to the margin on the side of the
+    /// [binding]($func/page.binding), independently of the margins
+    /// themselves. It accounts for the part of the paper that is lost in the
+    /// spine. With [two-sided margins]($func/page.margin), it moves from side
+    /// to side along with the inside margin.
+    ///
+    /// ```example
+    /// #set page(
+    ///   margin: (inside: 1cm, outside: 1cm, y: 1cm),
+    ///   binding-offset: 1cm,
+    ///   height: 3cm,
+    /// )
+    /// Away from the spine.
+    /// #pagebreak()
+    /// Also away from the spine.
+    /// ```
+    #[resolve]
+    pub binding_offset: Length,
+
     /// How many columns the page has.
     ///
     /// ```example:single
@@ -198,6 +222,29 @@ pub struct PageElem {
     #[default(Align::Center.into())]
     pub number_align: Axes<Option<GenAlign>>,
 
+    /// Whether and how to number the lines of the page's body.
+    ///
+    /// The numbers are placed in the margin, on the baseline of each line of
+    /// text. Lines in the header and footer are not numbered. Numbering
+    /// continues across page breaks as long as this setting stays the same.
+    ///
+    /// Can be set to a dictionary with the following keys:
+    /// - `start`: The number of the first line. Defaults to `{1}`.
+    /// - `step`: Only lines whose number is a multiple of the step are
+    ///   labelled. Defaults to `{1}`.
+    /// - `side`: The margin to place the numbers in, either `{left}` or
+    ///   `{right}`. Defaults to `{start}`.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 100pt,
+    ///   line-numbers: (step: 2),
+    /// )
+    ///
+    /// #lorem(40)
+    /// ```
+    pub line_numbers: Option<LineNumbers>,
+
     /// The page's header. Fills the top margin of each page.
     ///
     /// ```example
@@ -302,12 +349,18 @@ impl PageElem {
     /// while we post-process the pages in this function. This function returns
     /// a fragment consisting of multiple frames, one per output page of this
     /// page run.
+    ///
+    /// The `lines` hold the line numbering of the previous page run along with
+    /// the number of its next line. If this run numbers its lines in the same
+    /// way, the numbering continues from there. They are updated for the next
+    /// run.
     #[tracing::instrument(skip_all)]
     pub fn layout(
         &self,
         vt: &mut Vt,
         styles: StyleChain,
         mut number: NonZeroUsize,
+        lines: &mut Option<(LineNumbers, usize)>,
     ) -> SourceResult<Fragment> {
         tracing::info!("Page layout");
 
@@ -350,7 +403,9 @@ impl PageElem {
             child = ColumnsElem::new(child).with_count(columns).pack();
         }
 
-        let area = size - margin.sum_by_axis();
+        // The binding offset is reserved at the bound side of each page.
+        let offset = self.binding_offset(styles);
+        let area = size - margin.sum_by_axis() - Size::with_x(offset);
         let mut regions = Regions::repeat(area, area.map(Abs::is_finite));
         regions.root = true;
 
@@ -381,6 +436,12 @@ impl PageElem {
         });
         let footer_descent = self.footer_descent(styles);
 
+        let line_numbers = self.line_numbers(styles);
+        let mut line = line_numbers.map(|numbers| match *lines {
+            Some((previous, next)) if previous == numbers => next,
+            _ => numbers.start,
+        });
+
         let numbering_meta = FrameItem::Meta(
             Meta::PageNumbering(self.numbering(styles).into_value()),
             Size::zero(),
@@ -393,14 +454,43 @@ impl PageElem {
             // The padded width of the page's content without margins.
             let pw = frame.width();
 
+            // The binding offset is reserved at the bound side. If two sided,
+            // that's the inside, which starts out on the left and is swapped
+            // along with the margins below.
+            let mut margin = margin;
+            if two_sided || binding == Binding::Left {
+                margin.left += offset;
+            } else {
+                margin.right += offset;
+            }
+
             // If two sided, left becomes inside and right becomes outside.
             // Thus, for left-bound pages, we want to swap on even pages and
             // for right-bound pages, we want to swap on odd pages.
-            let mut margin = margin;
             if two_sided && binding.swap(number) {
                 std::mem::swap(&mut margin.left, &mut margin.right);
             }
 
+            // Realize line numbers.
+            if let (Some(numbers), Some(line)) = (line_numbers, &mut line) {
+                let gap = Em::one().resolve(styles);
+                let side = numbers.side.0.resolve(styles);
+                for y in line_baselines(frame) {
+                    if *line % numbers.step.get() == 0 {
+                        let number = TextElem::packed(eco_format!("{line}"));
+                        let pod =
+                            Regions::one(Size::splat(Abs::inf()), Axes::splat(false));
+                        let sub = number.layout(vt, styles, pod)?.into_frame();
+                        let x = match side {
+                            Align::Right => pw + gap,
+                            _ => -gap - sub.width(),
+                        };
+                        frame.push_frame(Point::new(x, y - sub.baseline()), sub);
+                    }
+                    *line += 1;
+                }
+            }
+
             // Realize margins.
             frame.set_size(frame.size() + margin.sum_by_axis());
             frame.translate(Point::new(margin.left, margin.top));
@@ -458,10 +548,67 @@ impl PageElem {
             number = number.saturating_add(1);
         }
 
+        *lines = line_numbers.zip(line);
         Ok(Fragment::frames(frames))
     }
 }
 
+/// How to number the lines of a page's body.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LineNumbers {
+    /// The number of the first line.
+    pub start: usize,
+    /// Only every `step`-th line is labelled.
+    pub step: NonZeroUsize,
+    /// The margin the numbers are placed in.
+    pub side: HorizontalAlign,
+}
+
+cast! {
+    LineNumbers,
+    self => dict! {
+        "start" => self.start,
+        "step" => self.step,
+        "side" => self.side,
+    }.into_value(),
+    mut dict: Dict => {
+        let start = dict.take("start").ok().map(Value::cast).transpose()?.unwrap_or(1);
+        let step = dict.take("step").ok().map(Value::cast).transpose()?
+            .unwrap_or(NonZeroUsize::ONE);
+        let side = dict.take("side").ok().map(Value::cast).transpose()?
+            .unwrap_or(HorizontalAlign(GenAlign::Start));
+        if side.0 == GenAlign::Specific(Align::Center) {
+            bail!("line numbers must be placed on the left or right");
+        }
+        dict.finish(&["start", "step", "side"])?;
+        Self { start, step, side }
+    },
+}
+
+/// Find the baselines of all lines of text in a frame, from top to bottom.
+///
+/// Lines that share a baseline, e.g. in adjacent columns, are only returned
+/// once.
+fn line_baselines(frame: &Frame) -> Vec<Abs> {
+    fn collect(frame: &Frame, offset: Abs, baselines: &mut Vec<Abs>) {
+        for (pos, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) if group.transform.is_identity() => {
+                    collect(&group.frame, offset + pos.y, baselines)
+                }
+                FrameItem::Meta(Meta::Line, _) => baselines.push(offset + pos.y),
+                _ => {}
+            }
+        }
+    }
+
+    let mut baselines = vec![];
+    collect(frame, Abs::zero(), &mut baselines);
+    baselines.sort();
+    baselines.dedup_by(|a, b| a.approx_eq(*b));
+    baselines
+}
+
 /// Specification of the page's margins.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Margin {
@@ -9,7 +9,7 @@ use typst::model::DelayedErrors;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
 
-use super::{BoxElem, HElem, Sizing, Spacing};
+use super::{BoxElem, HElem, PageElem, Sizing, Spacing};
 use crate::layout::AlignElem;
 use crate::math::EquationElem;
 use crate::prelude::*;
@@ -53,6 +53,21 @@ pub struct ParElem {
     #[default(Em::new(0.65).into())]
     pub leading: Length,
 
+    /// The minimum height of a line.
+    ///
+    /// Each line is as tall as the tallest content on it, so that larger text
+    /// or inline objects never overlap the neighbouring lines. If the content
+    /// is shorter than this minimum, the difference is split evenly above
+    /// and below it.
+    ///
+    /// ```example
+    /// #set par(min-line-height: 1.2em)
+    /// Tall #text(2em)[and] short lines
+    /// are spaced evenly.
+    /// ```
+    #[resolve]
+    pub min_line_height: Length,
+
     /// Whether to justify text in its line.
     ///
     /// Hyphenation will be enabled for justified paragraphs if the [text
@@ -65,6 +80,20 @@ pub struct ParElem {
     #[default(false)]
     pub justify: bool,
 
+    /// How to align the last line of the paragraph.
+    ///
+    /// When set to `{auto}`, the last line follows the current
+    /// [alignment]($func/align) like all other lines. With justified text,
+    /// this allows to center or right-align just the closing line, e.g. for
+    /// signatures.
+    ///
+    /// ```example
+    /// #set par(justify: true, last-line: center)
+    /// #lorem(16)
+    /// ```
+    #[default]
+    pub last_line: Smart<HorizontalAlign>,
+
     /// How to determine line breaks.
     ///
     /// When this property is set to `{auto}`, its default value, optimized line
@@ -91,6 +120,69 @@ pub struct ParElem {
     #[default]
     pub linebreaks: Smart<Linebreaks>,
 
+    /// How much to discourage line breaks at hyphenation opportunities.
+    ///
+    /// Higher values lead to fewer hyphenated lines at the cost of looser
+    /// spacing, lower values to tighter spacing with more hyphens. The penalty
+    /// is on the scale used by Knuth and Plass, where `{50}` is the default and
+    /// `{10000}` practically rules out hyphenation. This only has an effect
+    /// with [optimized]($func/par.linebreaks) line breaks.
+    ///
+    /// ```example
+    /// #set page(width: 160pt)
+    /// #set par(justify: true)
+    /// #set text(hyphenate: true)
+    /// #lorem(20)
+    ///
+    /// #set par(hyphenation-penalty: 1000)
+    /// #lorem(20)
+    /// ```
+    #[default(50.0)]
+    pub hyphenation_penalty: f64,
+
+    /// The maximum number of consecutive lines that may end with a hyphen.
+    ///
+    /// Just like the hyphenation penalty, this only has an effect with
+    /// [optimized]($func/par.linebreaks) line breaks. If there is no other
+    /// way to break a paragraph, the limit may still be exceeded.
+    #[default]
+    pub consecutive_hyphens: Option<NonZeroUsize>,
+
+    /// How far a line must stick out of the paragraph before a warning is
+    /// reported.
+    ///
+    /// By default, overfull lines are not reported. When set to a length, a
+    /// warning is emitted for lines that are wider than the paragraph by more
+    /// than this amount after all their spaces were shrunk as far as possible.
+    /// The warning points to the text the line starts with, so multiple bad
+    /// lines within the same piece of text are reported only once.
+    ///
+    /// ```example
+    /// #set page(width: 100pt)
+    /// #set par(overfull-tolerance: 1pt)
+    /// Supercalifragilisticexpialidocious
+    /// ```
+    #[default]
+    pub overfull_tolerance: Option<Length>,
+
+    /// How far the spaces of a justified line may be stretched before a
+    /// warning is reported.
+    ///
+    /// The amount is relative to the natural stretchability of the line's
+    /// spaces, so that `{100%}` reports lines whose spacing looks looser than
+    /// intended. Like overfull lines, multiple underfull lines within the same
+    /// piece of text are reported only once. By default, underfull lines are
+    /// not reported.
+    ///
+    /// ```example
+    /// #set page(width: 100pt)
+    /// #set par(justify: true, underfull-tolerance: 300%)
+    /// Just a very few
+    /// words.
+    /// ```
+    #[default]
+    pub underfull_tolerance: Option<Ratio>,
+
     /// The indent the first line of a paragraph should have.
     ///
     /// Only the first line of a consecutive paragraph will be indented (not
@@ -268,6 +360,8 @@ struct Preparation<'a> {
     lang: Option<Lang>,
     /// The paragraph's resolved alignment.
     align: Align,
+    /// The resolved alignment of the paragraph's last line.
+    last_align: Align,
     /// Whether to justify the paragraph.
     justify: bool,
     /// The paragraph's hanging indent.
@@ -690,7 +784,13 @@ fn prepare<'a>(
         let end = cursor + segment.len();
         match segment {
             Segment::Text(_) => {
+                let start = items.len();
                 shape_range(&mut items, vt, &bidi, cursor..end, &spans, styles);
+                for item in &items[start..] {
+                    if let Item::Text(shaped) = item {
+                        shaped.check_missing()?;
+                    }
+                }
             }
             Segment::Spacing(spacing) => match spacing {
                 Spacing::Rel(v) => {
@@ -727,6 +827,7 @@ fn prepare<'a>(
         cursor = end;
     }
 
+    let align = AlignElem::alignment_in(styles).x.resolve(styles);
     Ok(Preparation {
         bidi,
         items,
@@ -734,7 +835,11 @@ fn prepare<'a>(
         styles,
         hyphenate: shared_get(styles, children, TextElem::hyphenate_in),
         lang: shared_get(styles, children, TextElem::lang_in),
-        align: AlignElem::alignment_in(styles).x.resolve(styles),
+        align,
+        last_align: match ParElem::last_line_in(styles) {
+            Smart::Auto => align,
+            Smart::Custom(last) => last.0.resolve(styles),
+        },
         justify: ParElem::justify_in(styles),
         hang: ParElem::hanging_indent_in(styles),
     })
@@ -904,10 +1009,12 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
         pred: usize,
         total: Cost,
         line: Line<'a>,
+        hyphens: usize,
     }
 
     // Cost parameters.
-    const HYPH_COST: Cost = 0.5;
+    let hyph_cost = ParElem::hyphenation_penalty_in(p.styles) / 100.0;
+    let max_hyphens = ParElem::consecutive_hyphens_in(p.styles);
     const RUNT_COST: Cost = 0.5;
     const CONSECUTIVE_DASH_COST: Cost = 300.0;
     const MAX_COST: Cost = 1_000_000.0;
@@ -919,6 +1026,7 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
         pred: 0,
         total: 0.0,
         line: line(vt, p, 0..0, false, false),
+        hyphens: 0,
     }];
 
     let em = TextElem::size_in(p.styles);
@@ -991,8 +1099,9 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
             }
 
             // Penalize hyphens.
+            let hyphens = if hyphen { pred.hyphens + 1 } else { 0 };
             if hyphen {
-                cost += HYPH_COST;
+                cost += hyph_cost;
             }
 
             // In Knuth paper, cost = (1 + 100|r|^3 + p)^2 + a,
@@ -1001,6 +1110,11 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
             // where p=0.5 and a=300
             cost = (0.01 + cost).powi(2);
 
+            // Rule out too many consecutive hyphenated lines.
+            if max_hyphens.map_or(false, |max| hyphens > max.get()) {
+                cost = cost.max(MAX_COST);
+            }
+
             // Penalize two consecutive dashes (not necessarily hyphens) extra.
             if attempt.dash && pred.line.dash {
                 cost += CONSECUTIVE_DASH_COST;
@@ -1011,7 +1125,7 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
 
             // If this attempt is better than what we had before, take it!
             if best.as_ref().map_or(true, |best| best.total >= total) {
-                best = Some(Entry { pred: i, total, line: attempt });
+                best = Some(Entry { pred: i, total, line: attempt, hyphens });
             }
         }
 
@@ -1106,7 +1220,7 @@ struct Breakpoints<'a> {
     /// The inner iterator over the unicode line break opportunities.
     linebreaks: LineBreakIteratorUtf8<'a, 'a>,
     /// Iterator over syllables of the current word.
-    syllables: Option<hypher::Syllables<'a>>,
+    syllables: Option<Syllables<'a>>,
     /// The current text offset.
     offset: usize,
     /// The trimmed end of the current word.
@@ -1123,7 +1237,7 @@ impl Iterator for Breakpoints<'_> {
     fn next(&mut self) -> Option<Self::Item> {
         // If we're currently in a hyphenated "word", process the next syllable.
         if let Some(syllable) = self.syllables.as_mut().and_then(Iterator::next) {
-            self.offset += syllable.len();
+            self.offset += syllable;
             if self.offset == self.suffix {
                 self.offset = self.end;
             }
@@ -1153,14 +1267,21 @@ impl Iterator for Breakpoints<'_> {
                 ) || self.end == self.p.bidi.text.len()
             });
 
-        // Hyphenate the next word.
+        // Hyphenate the next word, preferring exceptions over patterns.
         if self.p.hyphenate != Some(false) {
-            if let Some(lang) = self.lang(self.offset) {
-                let word = &self.p.bidi.text[self.offset..self.end];
-                let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
-                if !trimmed.is_empty() {
+            let word = &self.p.bidi.text[self.offset..self.end];
+            let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
+            if !trimmed.is_empty() {
+                let syllables = match self.exception(self.offset, trimmed) {
+                    Some(lengths) => Some(Syllables::Exception(lengths.into_iter())),
+                    None => self.lang(self.offset).map(|lang| {
+                        Syllables::Patterns(hypher::hyphenate(trimmed, lang))
+                    }),
+                };
+
+                if syllables.is_some() {
                     self.suffix = self.offset + trimmed.len();
-                    self.syllables = Some(hypher::hyphenate(trimmed, lang));
+                    self.syllables = syllables;
                     return self.next();
                 }
             }
@@ -1183,6 +1304,51 @@ impl Breakpoints<'_> {
             .unwrap_or(false)
     }
 
+    /// The byte lengths of the syllables of a word with a hyphenation
+    /// exception at the given offset.
+    fn exception(&self, offset: usize, word: &str) -> Option<Vec<usize>> {
+        let shaped = self.p.find(offset)?.text()?;
+        let exceptions = TextElem::hyphenation_exceptions_in(shaped.styles);
+        let lower = |c: char| c.to_lowercase();
+        let exception = exceptions.iter().find(|exception| {
+            exception
+                .chars()
+                .filter(|&c| c != '-')
+                .flat_map(lower)
+                .eq(word.chars().flat_map(lower))
+        })?;
+
+        // Lowercasing can change the number of characters, so the hyphens
+        // are located in the lowercased text and mapped back to byte offsets
+        // in the original word.
+        let mut hyphens = vec![];
+        let mut count = 0;
+        for c in exception.chars() {
+            if c == '-' {
+                hyphens.push(count);
+            } else {
+                count += c.to_lowercase().count();
+            }
+        }
+
+        let mut hyphens = hyphens.into_iter().peekable();
+        let mut lengths = vec![];
+        let mut count = 0;
+        let mut last = 0;
+        for (i, c) in word.char_indices() {
+            // Skip hyphens that fall into the lowercase form of a character.
+            while hyphens.next_if(|&hyphen| hyphen < count).is_some() {}
+            if hyphens.next_if_eq(&count).is_some() && i > last {
+                lengths.push(i - last);
+                last = i;
+            }
+            count += c.to_lowercase().count();
+        }
+
+        lengths.push(word.len() - last);
+        Some(lengths)
+    }
+
     /// The text language at the given offset.
     fn lang(&self, offset: usize) -> Option<hypher::Lang> {
         let lang = self.p.lang.or_else(|| {
@@ -1195,6 +1361,25 @@ impl Breakpoints<'_> {
     }
 }
 
+/// The syllables of a word, as byte lengths.
+enum Syllables<'a> {
+    /// Syllables determined by the language's hyphenation patterns.
+    Patterns(hypher::Syllables<'a>),
+    /// Syllables from a hyphenation exception.
+    Exception(std::vec::IntoIter<usize>),
+}
+
+impl Iterator for Syllables<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Patterns(syllables) => syllables.next().map(str::len),
+            Self::Exception(lengths) => lengths.next(),
+        }
+    }
+}
+
 /// Create a line which spans the given range.
 fn line<'a>(
     vt: &Vt,
@@ -1363,6 +1548,14 @@ fn finalize(
         .map(|line| commit(vt, p, line, width, region.y))
         .collect::<SourceResult<_>>()?;
 
+    // Mark the baselines for line numbering.
+    if PageElem::line_numbers_in(p.styles).is_some() {
+        for frame in &mut frames {
+            let marker = FrameItem::Meta(Meta::Line, Size::zero());
+            frame.push(Point::with_y(frame.baseline()), marker);
+        }
+    }
+
     // Prevent orphans.
     let leading = ParElem::leading_in(p.styles);
     if frames.len() >= 2 && !frames[1].is_empty() {
@@ -1450,6 +1643,14 @@ fn commit(
         justification_ratio = (remaining / shrink).max(-1.0);
         remaining = (remaining + shrink).min(Abs::zero());
     } else if line.justify && fr.is_zero() {
+        if let Some(tolerance) = ParElem::underfull_tolerance_in(p.styles) {
+            if remaining > Abs::zero()
+                && (stretch <= Abs::zero() || remaining / stretch > tolerance.get())
+            {
+                report(vt, line, "line is underfull", "its spaces are stretched too far");
+            }
+        }
+
         // Attempt to increase the length of the line, using stretchability.
         if stretch > Abs::zero() {
             justification_ratio = (remaining / stretch).min(1.0);
@@ -1464,6 +1665,13 @@ fn commit(
         }
     }
 
+    if let Some(tolerance) = ParElem::overfull_tolerance_in(p.styles) {
+        if fr.is_zero() && -remaining > tolerance.resolve(p.styles) {
+            let hint = eco_format!("it is {:?} too wide", -remaining);
+            report(vt, line, "line is overfull", &hint);
+        }
+    }
+
     let mut top = Abs::zero();
     let mut bottom = Abs::zero();
 
@@ -1504,6 +1712,14 @@ fn commit(
         }
     }
 
+    // Pad short lines to the minimum height.
+    let min = ParElem::min_line_height_in(p.styles);
+    let missing = min - (top + bottom);
+    if missing > Abs::zero() {
+        top += missing / 2.0;
+        bottom += missing / 2.0;
+    }
+
     // Remaining space is distributed now.
     if !fr.is_zero() {
         remaining = Abs::zero();
@@ -1514,8 +1730,9 @@ fn commit(
     output.set_baseline(top);
 
     // Construct the line's frame.
+    let align = if line.end == p.bidi.text.len() { p.last_align } else { p.align };
     for (offset, frame) in frames {
-        let x = offset + p.align.position(remaining);
+        let x = offset + align.position(remaining);
         let y = top - frame.baseline();
         output.push_frame(Point::new(x, y), frame);
     }
@@ -1523,6 +1740,20 @@ fn commit(
     Ok(output)
 }
 
+/// Report a badly filled line as a warning at its first text.
+fn report(vt: &mut Vt, line: &Line, message: &str, hint: &str) {
+    let span = line
+        .items()
+        .filter_map(Item::text)
+        .flat_map(|text| text.glyphs.iter())
+        .map(|glyph| glyph.span.0)
+        .find(|span| !span.is_detached());
+
+    if let Some(span) = span {
+        vt.tracer.warn(warning!(span, "{message}").with_hint(hint));
+    }
+}
+
 /// Return a line's items in visual order.
 fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&Item<'a>>, bool) {
     let mut reordered = vec![];
@@ -3,6 +3,19 @@ use std::fmt::{self, Debug, Formatter};
 use typst::geom::{Abs, Axes, Size};
 
 /// A sequence of regions to layout into.
+///
+/// Content that can break across pages or columns is laid out into a sequence
+/// of regions: The first region with the remaining `size`, then one region per
+/// height in the `backlog` and finally, if `last` is set, an infinite
+/// repetition of the `last` height. All regions share the same width.
+///
+/// A [`Layout`](super::Layout) implementation produces one frame per region it
+/// uses, which it returns as a [`Fragment`](super::Fragment). Containers that
+/// lay out their children into smaller regions (like padding does) should
+/// derive them with [`map()`](Self::map) and then post-process each frame of
+/// the child's fragment. Implementations that lay out several children one
+/// after another should fill the current region and call
+/// [`next()`](Self::next) to continue in the following one.
 #[derive(Copy, Clone, Hash)]
 pub struct Regions<'a> {
     /// The remaining size of the first region.
@@ -59,8 +72,13 @@ impl Regions<'_> {
 
     /// Create new regions where all sizes are mapped with `f`.
     ///
+    /// The mapped backlog heights are written into the provided `backlog`
+    /// vector, which the resulting regions borrow. Its previous contents are
+    /// discarded, so one vector can be reused across calls.
+    ///
     /// Note that since all regions must have the same width, the width returned
-    /// by `f` is ignored for the backlog and the final region.
+    /// by `f` is ignored for the backlog and the final region. The result is
+    /// never a root region.
     pub fn map<'v, F>(&self, backlog: &'v mut Vec<Abs>, mut f: F) -> Regions<'v>
     where
         F: FnMut(Size) -> Size,
@@ -1,8 +1,9 @@
 use typst::eval::{CastInfo, Reflect};
 
-use crate::layout::{AlignElem, GridLayouter, TrackSizings};
+use crate::layout::{place_cells, AlignElem, GridCellElem, GridLayouter, TrackSizings};
 use crate::meta::{Figurable, LocalName};
 use crate::prelude::*;
+use crate::text::measure_text;
 
 /// A table of items.
 ///
@@ -38,6 +39,10 @@ use crate::prelude::*;
 /// Display: Table
 /// Category: layout
 #[element(Layout, LocalName, Figurable)]
+#[scope(
+    scope.define("cell", GridCellElem::func());
+    scope
+)]
 pub struct TableElem {
     /// The column sizes. See the [grid documentation]($func/grid) for more
     /// information on track sizing.
@@ -65,6 +70,11 @@ pub struct TableElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// How many rows at the top of the table form its header. See the [grid
+    /// documentation]($func/grid.header-rows) for more information.
+    #[default(0)]
+    pub header_rows: usize,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function is
@@ -94,6 +104,21 @@ pub struct TableElem {
     /// The function is passed the cells' column and row index, starting at zero.
     /// If set to `{auto}`, the outer alignment is used.
     ///
+    /// The special value `{"decimal"}` aligns numbers on their decimal point:
+    /// The numbers in a column are padded so that their `.` separators line
+    /// up and are then aligned to the right. Numbers without a decimal point
+    /// are aligned as if it followed their last digit.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   align: (left, "decimal"),
+    ///   [Apples], [1.5],
+    ///   [Pears], [12.25],
+    ///   [Plums], [100],
+    /// )
+    /// ```
+    ///
     /// ```example
     /// #table(
     ///   columns: 3,
@@ -102,20 +127,38 @@ pub struct TableElem {
     ///   [A], [B], [C],
     /// )
     /// ```
-    pub align: Celled<Smart<Axes<Option<GenAlign>>>>,
+    pub align: Celled<Smart<CellAlign>>,
 
     /// How to stroke the cells.
     ///
     /// See the [line's documentation]($func/line.stroke) for more details.
     /// Strokes can be disabled by setting this to `{none}`.
     ///
-    /// _Note:_ Richer stroke customization for individual cells is not yet
-    /// implemented, but will be in the future. In the meantime, you can use
-    /// the third-party [tablex library](https://github.com/PgBiel/typst-tablex/).
-    #[resolve]
+    /// A dictionary with the keys `left`, `top`, `right`, `bottom`, `x`, `y`
+    /// and `rest` strokes only some sides of each cell. Like for fills and
+    /// alignments, this can also be an array of strokes (corresponding to each
+    /// column) or a function that returns a stroke. The function is passed the
+    /// cells' column and row index, starting at zero. Where two adjacent cells
+    /// both stroke their shared edge, the stroke of the cell below or to the
+    /// right is used.
+    ///
+    /// Set rules for strokes that are the same for all sides of all cells
+    /// combine, so that `{red}` keeps a thickness set earlier. Arrays and
+    /// functions replace earlier strokes.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   fill: (col, row) => if row == 0 { luma(230) },
+    ///   stroke: (col, row) => (top: if row > 0 { 0.5pt }),
+    ///   [*Name*], [*Age*], [*City*],
+    ///   [Anna], [32], [Berlin],
+    ///   [Ben], [27], [Paris],
+    /// )
+    /// ```
     #[fold]
-    #[default(Some(PartialStroke::default()))]
-    pub stroke: Option<PartialStroke>,
+    #[default(Celled::Value(Sides::splat(Some(Some(PartialStroke::default())))))]
+    pub stroke: Celled<Sides<Option<Option<PartialStroke>>>>,
 
     /// How much to pad the cells' content.
     #[default(Abs::pt(5.0).into())]
@@ -140,25 +183,83 @@ impl Layout for TableElem {
         let tracks = Axes::new(self.columns(styles).0, self.rows(styles).0);
         let gutter = Axes::new(self.column_gutter(styles).0, self.row_gutter(styles).0);
         let cols = tracks.x.len().max(1);
-        let cells: Vec<_> = self
-            .children()
+        let children = self.children();
+        let placed = place_cells(&children, cols, styles);
+        let aligns = placed
+            .iter()
+            .map(|&(x, y, _)| align.resolve(vt, x, y))
+            .collect::<SourceResult<Vec<_>>>()?;
+
+        // Unwrap spanning cells so that their bodies can be styled. They are
+        // wrapped again below.
+        let children: Vec<_> = children
+            .into_iter()
+            .map(|child| match child.to::<GridCellElem>() {
+                Some(cell) => cell.body(),
+                None => child,
+            })
+            .collect();
+
+        // Measure decimal-aligned numbers left and right of their decimal
+        // point and find the widest parts in each column.
+        let parts: Vec<_> = children
+            .iter()
+            .zip(&aligns)
+            .map(|(child, align)| {
+                (*align == Smart::Custom(CellAlign::Decimal))
+                    .then(|| measure_decimal(vt, child, styles))
+                    .transpose()
+            })
+            .collect::<SourceResult<Vec<_>>>()?;
+
+        let mut widest = vec![(Abs::zero(), Abs::zero()); cols];
+        for (part, &(x, _, _)) in parts.iter().zip(&placed) {
+            if let Some((int, frac)) = part {
+                let (max_int, max_frac) = &mut widest[x];
+                max_int.set_max(*int);
+                max_frac.set_max(*frac);
+            }
+        }
+
+        let cells: Vec<_> = children
             .into_iter()
-            .enumerate()
-            .map(|(i, child)| {
-                let mut child = child.padded(Sides::splat(inset));
-
-                let x = i % cols;
-                let y = i / cols;
-                if let Smart::Custom(alignment) = align.resolve(vt, x, y)? {
-                    child = child.styled(AlignElem::set_alignment(alignment));
+            .zip(aligns)
+            .zip(parts)
+            .zip(&placed)
+            .map(|(((mut child, align), part), &(x, _, span))| {
+                if let Some((int, frac)) = part {
+                    let (max_int, max_frac) = widest[x];
+                    let zero = Rel::zero();
+                    child = child.padded(Sides::new(
+                        (max_int - int).into(),
+                        zero,
+                        (max_frac - frac).into(),
+                        zero,
+                    ));
                 }
 
-                Ok(child)
-            })
-            .collect::<SourceResult<_>>()?;
+                child = child.padded(Sides::splat(inset));
+                match align {
+                    Smart::Custom(CellAlign::Align(alignment)) => {
+                        child = child.styled(AlignElem::set_alignment(alignment));
+                    }
+                    Smart::Custom(CellAlign::Decimal) => {
+                        let right = GenAlign::Specific(Align::Right);
+                        child = child
+                            .styled(AlignElem::set_alignment(Axes::with_x(Some(right))));
+                    }
+                    Smart::Auto => {}
+                }
+
+                if span > 1 {
+                    child = GridCellElem::new(child)
+                        .with_colspan(NonZeroUsize::new(span).unwrap())
+                        .pack();
+                }
 
-        let fill = self.fill(styles);
-        let stroke = self.stroke(styles).map(PartialStroke::unwrap_or_default);
+                child
+            })
+            .collect();
 
         // Prepare grid layout by unifying content and gutter tracks.
         let layouter = GridLayouter::new(
@@ -167,74 +268,60 @@ impl Layout for TableElem {
             &cells,
             regions,
             styles,
-        );
+        )
+        .with_header(self.header_rows(styles));
 
         // Measure the columns and layout the grid row-by-row.
         let mut layout = layouter.layout(vt)?;
+        layout.decorate(
+            vt,
+            styles,
+            &self.fill(styles),
+            &self.stroke(styles),
+            self.span(),
+        )?;
 
-        // Add lines and backgrounds.
-        for (frame, rows) in layout.fragment.iter_mut().zip(&layout.rows) {
-            if layout.cols.is_empty() || rows.is_empty() {
-                continue;
-            }
-
-            // Render table lines.
-            if let Some(stroke) = &stroke {
-                let thickness = stroke.thickness;
-                let half = thickness / 2.0;
-
-                // Render horizontal lines.
-                for offset in points(rows.iter().map(|piece| piece.height)) {
-                    let target = Point::with_x(frame.width() + thickness);
-                    let hline = Geometry::Line(target).stroked(stroke.clone());
-                    frame.prepend(
-                        Point::new(-half, offset),
-                        FrameItem::Shape(hline, self.span()),
-                    );
-                }
+        Ok(layout.fragment)
+    }
+}
 
-                // Render vertical lines.
-                for offset in points(layout.cols.iter().copied()) {
-                    let target = Point::with_y(frame.height() + thickness);
-                    let vline = Geometry::Line(target).stroked(stroke.clone());
-                    frame.prepend(
-                        Point::new(offset, -half),
-                        FrameItem::Shape(vline, self.span()),
-                    );
-                }
-            }
+/// Measure a number left and right of its decimal point.
+///
+/// The number is measured with the cell's own styles applied, so that a cell
+/// with, for example, a different text size is padded correctly.
+fn measure_decimal(
+    vt: &Vt,
+    child: &Content,
+    styles: StyleChain,
+) -> SourceResult<(Abs, Abs)> {
+    if let Some((body, local)) = child.to_styled() {
+        return measure_decimal(vt, body, styles.chain(local));
+    }
 
-            // Render cell backgrounds.
-            let mut dx = Abs::zero();
-            for (x, &col) in layout.cols.iter().enumerate() {
-                let mut dy = Abs::zero();
-                for row in rows {
-                    if let Some(fill) = fill.resolve(vt, x, row.y)? {
-                        let pos = Point::new(dx, dy);
-                        let size = Size::new(col, row.height);
-                        let rect = Geometry::Rect(size).filled(fill);
-                        frame.prepend(pos, FrameItem::Shape(rect, self.span()));
-                    }
-                    dy += row.height;
-                }
-                dx += col;
-            }
-        }
+    let text = child.plain_text();
+    let (int, frac) = text.split_at(text.find('.').unwrap_or(text.len()));
+    let span = child.span();
+    Ok((measure_text(vt, int, span, styles)?.x, measure_text(vt, frac, span, styles)?.x))
+}
 
-        Ok(layout.fragment)
-    }
+/// How to align the content of a table cell.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CellAlign {
+    /// Align the content like the [`align`]($func/align) function.
+    Align(Axes<Option<GenAlign>>),
+    /// Align numbers on their decimal point.
+    Decimal,
 }
 
-/// Turn an iterator of extents into an iterator of offsets before, in between,
-/// and after the extents, e.g. [10mm, 5mm] -> [0mm, 10mm, 15mm].
-fn points(extents: impl IntoIterator<Item = Abs>) -> impl Iterator<Item = Abs> {
-    let mut offset = Abs::zero();
-    std::iter::once(Abs::zero())
-        .chain(extents.into_iter())
-        .map(move |extent| {
-            offset += extent;
-            offset
-        })
+cast! {
+    CellAlign,
+    self => match self {
+        Self::Align(alignment) => alignment.into_value(),
+        Self::Decimal => "decimal".into_value(),
+    },
+    v: Axes<Option<GenAlign>> => Self::Align(v),
+    /// Align numbers on their decimal point.
+    "decimal" => Self::Decimal,
 }
 
 /// A value that can be configured per cell.
@@ -263,6 +350,22 @@ impl<T: Default + Clone + FromValue> Celled<T> {
     }
 }
 
+impl<T: Fold<Output = T>> Fold for Celled<Sides<Option<T>>> {
+    type Output = Self;
+
+    fn fold(self, outer: Self::Output) -> Self::Output {
+        match (self, outer) {
+            (Self::Value(inner), Self::Value(outer)) => {
+                Self::Value(inner.zip(outer).map(|(inner, outer)| match (inner, outer) {
+                    (Some(inner), Some(outer)) => Some(inner.fold(outer)),
+                    (inner, outer) => inner.or(outer),
+                }))
+            }
+            (inner, _) => inner,
+        }
+    }
+}
+
 impl<T: Default> Default for Celled<T> {
     fn default() -> Self {
         Self::Value(T::default())
@@ -1,4 +1,4 @@
-use super::{HElem, VElem};
+use super::{GridLayouter, HElem, Sizing, VElem};
 use crate::layout::{BlockElem, ParElem, Spacing};
 use crate::prelude::*;
 
@@ -79,6 +79,26 @@ pub struct TermsElem {
     #[default(Em::new(2.0).into())]
     pub hanging_indent: Length,
 
+    /// Where the descriptions start, measured from the item's `indent`.
+    ///
+    /// - `{none}`: Each description directly follows its term and the
+    ///   separator, with the hanging indent applied to its following lines.
+    /// - `{auto}`: All descriptions are aligned to a common tab stop `{1em}`
+    ///   after the widest term. Multi-line descriptions wrap under themselves.
+    /// - A length: Like `{auto}`, but with the tab stop at a fixed distance.
+    ///   Longer terms wrap within that width.
+    ///
+    /// In the aligned modes, the separator and the hanging indent are not
+    /// used.
+    ///
+    /// ```example
+    /// #set terms(tab-stop: auto)
+    /// / Ligature: A merged glyph.
+    /// / Kerning: A spacing adjustment
+    ///   between two adjacent letters.
+    /// ```
+    pub tab_stop: Option<Smart<Length>>,
+
     /// The spacing between the items of a wide (non-tight) term list.
     ///
     /// If set to `{auto}`, uses the spacing [below blocks]($func/block.below).
@@ -118,6 +138,36 @@ impl Layout for TermsElem {
                 .unwrap_or_else(|| BlockElem::below_in(styles).amount())
         };
 
+        if let Some(tab_stop) = self.tab_stop(styles) {
+            let mut cells = vec![];
+            for child in self.children() {
+                cells.push(Content::empty());
+                cells.push(child.term().strong());
+                cells.push(Content::empty());
+                cells.push(child.description());
+            }
+
+            let (term, gap) = match tab_stop {
+                Smart::Auto => (Sizing::Auto, Em::one().into()),
+                Smart::Custom(stop) => (Sizing::Rel(stop.into()), Length::zero()),
+            };
+
+            let layouter = GridLayouter::new(
+                Axes::with_x(&[
+                    Sizing::Rel(indent.into()),
+                    term,
+                    Sizing::Rel(gap.into()),
+                    Sizing::Fr(Fr::one()),
+                ]),
+                Axes::with_y(&[gutter.into()]),
+                &cells,
+                regions,
+                styles,
+            );
+
+            return Ok(layouter.layout(vt)?.fragment);
+        }
+
         let mut seq = vec![];
         for (i, child) in self.children().into_iter().enumerate() {
             if i > 0 {
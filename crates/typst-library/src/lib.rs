@@ -4,6 +4,7 @@
 #![allow(clippy::manual_range_contains)]
 #![allow(clippy::comparison_chain)]
 
+pub mod builder;
 pub mod compute;
 pub mod layout;
 pub mod math;
@@ -14,11 +15,17 @@ pub mod symbols;
 pub mod text;
 pub mod visualize;
 
+mod oneshot;
+
 use typst::diag::At;
 use typst::eval::{LangItems, Library, Module, Scope};
 use typst::geom::Smart;
 use typst::model::{Element, Styles};
 
+pub use self::oneshot::compile_to_pdf;
+#[cfg(feature = "embed-fonts")]
+pub use self::oneshot::embedded_fonts;
+
 use self::layout::LayoutRoot;
 
 /// Construct the standard library.
@@ -28,6 +35,26 @@ pub fn build() -> Library {
     Library { global, math, styles: styles(), items: items() }
 }
 
+/// The global functions that read files through the [`World`](typst::World).
+pub const FILE_FUNCTIONS: &[&str] =
+    &["read", "csv", "json", "toml", "yaml", "xml", "image", "bibliography"];
+
+/// Construct the standard library without the [functions that read
+/// files](FILE_FUNCTIONS).
+///
+/// Calling one of them in a document built with this library fails with an
+/// unknown variable error. This is meant for rendering untrusted input.
+/// Note that `import` and `include` as well as custom `raw` themes and
+/// syntaxes still load files, so a sandboxed world should additionally
+/// refuse to serve any file but the main source.
+pub fn build_restricted() -> Library {
+    let mut library = build();
+    for name in FILE_FUNCTIONS {
+        library.global.scope_mut().remove(name);
+    }
+    library
+}
+
 /// Construct the module with global definitions.
 #[tracing::instrument(skip_all)]
 fn global(math: Module) -> Module {
@@ -86,7 +113,13 @@ fn items() -> LangItems {
         bibliography_keys: meta::BibliographyElem::keys,
         heading: |level, title| meta::HeadingElem::new(title).with_level(level).pack(),
         heading_func: meta::HeadingElem::func(),
-        list_item: |body| layout::ListItem::new(body).pack(),
+        list_item: |checked, body| {
+            let mut elem = layout::ListItem::new(body);
+            if let Some(checked) = checked {
+                elem.push_checked(Some(checked));
+            }
+            elem.pack()
+        },
         enum_item: |number, body| {
             let mut elem = layout::EnumItem::new(body);
             if let Some(number) = number {
@@ -143,3 +176,17 @@ fn items() -> LangItems {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restricted_library() {
+        let library = build_restricted();
+        let global = library.global.scope();
+        assert!(FILE_FUNCTIONS.iter().all(|name| global.get(name).is_none()));
+        assert!(global.get("text").is_some());
+        assert!(build().global.scope().get("image").is_some());
+    }
+}
@@ -5,7 +5,9 @@ use typst::model::realize;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::*;
-use crate::text::{tags, BottomEdge, BottomEdgeMetric, TopEdge, TopEdgeMetric};
+use crate::text::{
+    missing_glyph, tags, BottomEdge, BottomEdgeMetric, TopEdge, TopEdgeMetric,
+};
 
 macro_rules! scaled {
     ($ctx:expr, text: $text:ident, display: $display:ident $(,)?) => {
@@ -195,7 +197,11 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
             let mut fragments = vec![];
             for c in text.chars() {
                 let c = self.style.styled_char(c);
-                fragments.push(GlyphFragment::new(self, c, span).into());
+                let glyph = GlyphFragment::new(self, c, span);
+                if glyph.id.0 == 0 {
+                    missing_glyph(self.styles(), c, span)?;
+                }
+                fragments.push(glyph.into());
             }
             let frame = MathRow::new(fragments).into_frame(self);
             FrameFragment::new(self, frame).into()
@@ -1,4 +1,6 @@
-use crate::layout::{LayoutRoot, PageElem};
+use typst::eval::Datetime;
+
+use crate::layout::{FlowElem, LayoutRoot, PageElem, VElem};
 use crate::prelude::*;
 
 /// The root element of a document and its metadata.
@@ -29,6 +31,36 @@ pub struct DocumentElem {
     /// The document's authors.
     pub author: Author,
 
+    /// The document's subject, a short description of what it is about.
+    pub subject: Option<EcoString>,
+
+    /// The document's keywords.
+    ///
+    /// Like the authors, this can be a single string or an array of strings.
+    pub keywords: Keywords,
+
+    /// The document's creation date.
+    ///
+    /// This is embedded into the PDF's metadata, e.g. as
+    /// `{datetime(year: 2023, month: 9, day: 4)}`. If it is `{none}`, no
+    /// date is embedded.
+    pub date: Option<Datetime>,
+
+    /// Whether to keep the last page if it has no content.
+    ///
+    /// A page break at the very end of the document starts a new, empty page.
+    /// If this is set to `{false}`, that page is dropped. Pages that were
+    /// explicitly created with the [`page`]($func/page) function are always
+    /// kept.
+    ///
+    /// ```example
+    /// #set document(keep-empty-last: false)
+    /// The end.
+    /// #pagebreak()
+    /// ```
+    #[default(true)]
+    pub keep_empty_last: bool,
+
     /// The page runs.
     #[internal]
     #[variadic]
@@ -48,8 +80,16 @@ impl LayoutRoot for DocumentElem {
         tracing::info!("Document layout");
 
         let mut pages = vec![];
+        let mut children = self.children();
+        if !self.keep_empty_last(styles)
+            && children.len() > 1
+            && children.last().map_or(false, is_empty_page)
+        {
+            children.pop();
+        }
 
-        for mut child in &self.children() {
+        let mut lines = None;
+        for mut child in &children {
             let outer = styles;
             let mut styles = styles;
             if let Some((elem, local)) = child.to_styled() {
@@ -59,8 +99,11 @@ impl LayoutRoot for DocumentElem {
 
             if let Some(page) = child.to::<PageElem>() {
                 let number = NonZeroUsize::ONE.saturating_add(pages.len());
-                let fragment = page.layout(vt, styles, number)?;
-                pages.extend(fragment);
+                let fragment = page.layout(vt, styles, number, &mut lines)?;
+                for frame in fragment {
+                    vt.tracer.page(pages.len(), frame.size());
+                    pages.push(frame);
+                }
             } else {
                 bail!(child.span(), "unexpected document child");
             }
@@ -70,10 +113,23 @@ impl LayoutRoot for DocumentElem {
             pages,
             title: self.title(styles),
             author: self.author(styles).0,
+            subject: self.subject(styles),
+            keywords: self.keywords(styles).0,
+            date: self.date(styles),
         })
     }
 }
 
+/// Whether a page run has no content, e.g. because it was only started by a
+/// page break.
+fn is_empty_page(child: &Content) -> bool {
+    let child = child.to_styled().map_or(child, |(elem, _)| elem);
+    child
+        .to::<PageElem>()
+        .and_then(|page| page.body().to::<FlowElem>().cloned())
+        .map_or(false, |flow| flow.children().iter().all(|c| c.is::<VElem>()))
+}
+
 /// A list of authors.
 #[derive(Debug, Default, Clone, Hash)]
 pub struct Author(Vec<EcoString>);
@@ -82,5 +138,21 @@ cast! {
     Author,
     self => self.0.into_value(),
     v: EcoString => Self(vec![v]),
-    v: Array => Self(v.into_iter().map(Value::cast).collect::<StrResult<_>>()?),
+    v: Array => Self(strings(v)?),
+}
+
+/// A list of keywords.
+#[derive(Debug, Default, Clone, Hash)]
+pub struct Keywords(Vec<EcoString>);
+
+cast! {
+    Keywords,
+    self => self.0.into_value(),
+    v: EcoString => Self(vec![v]),
+    v: Array => Self(strings(v)?),
+}
+
+/// Cast each value of an array to a string.
+fn strings(array: Array) -> StrResult<Vec<EcoString>> {
+    array.into_iter().map(Value::cast).collect()
 }
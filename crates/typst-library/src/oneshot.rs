@@ -0,0 +1,149 @@
+//! Compiling a document in a single call.
+
+use std::path::{Path, PathBuf};
+
+use comemo::Prehashed;
+use ecow::eco_format;
+use typst::diag::{FileError, FileResult, SourceDiagnostic, SourceResult};
+use typst::eval::{Bytes, Datetime, Library, Tracer};
+use typst::font::{Font, FontBook};
+use typst::syntax::{FileId, Source, Span};
+use typst::World;
+
+/// Compile Typst markup into a PDF file.
+///
+/// This sets up a minimal world with the standard library and the fonts from
+/// the given font files and exports the resulting document as PDF. The source
+/// cannot access other files and the current date is in UTC.
+///
+/// If the `embed-fonts` feature is enabled, the [embedded
+/// fonts](embedded_fonts) are always available, so that the document can be
+/// typeset without any font files.
+///
+/// For anything beyond a one-off conversion, implement [`World`] instead: It
+/// can load files and retain fonts and sources between compilations.
+pub fn compile_to_pdf(src: &str, fonts: &[PathBuf]) -> SourceResult<Vec<u8>> {
+    let world = OneShotWorld::new(src, fonts)?;
+    let mut tracer = Tracer::default();
+    let document = typst::compile(&world, &mut tracer)?;
+    Ok(typst::export::pdf(&document, &mut tracer))
+}
+
+/// A world with a single source file and a fixed set of fonts.
+struct OneShotWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    main: Source,
+}
+
+impl OneShotWorld {
+    /// Create the world, loading all fonts from the given files.
+    fn new(src: &str, paths: &[PathBuf]) -> SourceResult<Self> {
+        let mut fonts = vec![];
+        for path in paths {
+            let data = std::fs::read(path).map_err(|err| font_error(path, err))?;
+            fonts.extend(Font::iter(data.into()));
+        }
+
+        #[cfg(feature = "embed-fonts")]
+        fonts.extend(embedded_fonts());
+
+        Ok(Self {
+            library: Prehashed::new(crate::build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            main: Source::detached(src),
+        })
+    }
+}
+
+impl World for OneShotWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.main.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.main.id() {
+            Ok(self.main.clone())
+        } else {
+            Err(FileError::NotFound(id.path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        Err(FileError::NotFound(id.path().into()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, offset: Option<i64>) -> Option<Datetime> {
+        let now = time::OffsetDateTime::now_utc()
+            + time::Duration::hours(offset.unwrap_or_default());
+        Datetime::from_ymd(now.year(), now.month().into(), now.day())
+    }
+}
+
+/// The fonts that are embedded into the library.
+///
+/// These cover Latin text in Linux Libertine and New Computer Modern, math in
+/// New Computer Modern Math and code in DejaVu Sans Mono.
+#[cfg(feature = "embed-fonts")]
+pub fn embedded_fonts() -> impl Iterator<Item = Font> {
+    macro_rules! embed {
+        ($($filename:literal),* $(,)?) => {
+            [$(include_bytes!(concat!("../../../assets/fonts/", $filename)) as &[u8]),*]
+        };
+    }
+
+    let data: [&'static [u8]; 14] = embed![
+        "LinLibertine_R.ttf",
+        "LinLibertine_RB.ttf",
+        "LinLibertine_RBI.ttf",
+        "LinLibertine_RI.ttf",
+        "NewCMMath-Book.otf",
+        "NewCMMath-Regular.otf",
+        "NewCM10-Regular.otf",
+        "NewCM10-Bold.otf",
+        "NewCM10-Italic.otf",
+        "NewCM10-BoldItalic.otf",
+        "DejaVuSansMono.ttf",
+        "DejaVuSansMono-Bold.ttf",
+        "DejaVuSansMono-Oblique.ttf",
+        "DejaVuSansMono-BoldOblique.ttf",
+    ];
+
+    data.into_iter().flat_map(|data| Font::iter(Bytes::from_static(data)))
+}
+
+/// Turn a failure to read a font file into a diagnostic.
+fn font_error(path: &Path, err: std::io::Error) -> Box<Vec<SourceDiagnostic>> {
+    let error = FileError::from_io(err, path);
+    Box::new(vec![SourceDiagnostic::error(
+        Span::detached(),
+        eco_format!("failed to load font file ({error})"),
+    )])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_without_font_files() {
+        // Only the embedded fonts end up in the PDF.
+        let pdf = compile_to_pdf("Hello", &[]).unwrap();
+        let embeds = pdf.windows(b"/FontFile".len()).any(|w| w == b"/FontFile");
+        assert_eq!(embeds, cfg!(feature = "embed-fonts"));
+    }
+}
@@ -1,4 +1,4 @@
-use super::TextElem;
+use super::{TextDir, TextElem};
 use crate::prelude::*;
 
 /// A text space.
@@ -303,6 +303,45 @@ pub fn smallcaps(
     body.styled(TextElem::set_smallcaps(true))
 }
 
+/// Isolates content in a fixed direction from the surrounding text.
+///
+/// Within the isolated run, the body is ordered in the given direction
+/// regardless of the paragraph's direction, and its content does not affect
+/// the ordering of the text around it. This is useful for embedding things
+/// like file paths, code or identifiers into right-to-left text and vice
+/// versa.
+///
+/// ## Example { #example }
+/// ```example
+/// #set text(lang: "ar")
+/// الملف #isolate(ltr)[`src/main.rs`] مفقود
+/// ```
+///
+/// Display: Isolate
+/// Category: text
+#[func]
+pub fn isolate(
+    /// The direction of the isolated run. Must be `{ltr}` or `{rtl}`.
+    dir: Spanned<Dir>,
+    /// The content to isolate.
+    body: Content,
+) -> SourceResult<Content> {
+    if dir.v.axis() != Axis::X {
+        bail!(dir.span, "text direction must be horizontal");
+    }
+
+    let (start, end) = match dir.v {
+        Dir::RTL => ("\u{2067}", "\u{2069}"),
+        _ => ("\u{2066}", "\u{2069}"),
+    };
+
+    Ok(Content::sequence([
+        TextElem::packed(start),
+        body.styled(TextElem::set_dir(TextDir(Smart::Custom(dir.v)))),
+        TextElem::packed(end),
+    ]))
+}
+
 /// Creates blind text.
 ///
 /// This function yields a Latin-like _Lorem Ipsum_ blind text with the given
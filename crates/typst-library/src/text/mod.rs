@@ -2,6 +2,7 @@
 
 mod deco;
 mod misc;
+mod path;
 mod quotes;
 mod raw;
 mod shaping;
@@ -9,6 +10,7 @@ mod shift;
 
 pub use self::deco::*;
 pub use self::misc::*;
+pub use self::path::*;
 pub use self::quotes::*;
 pub use self::raw::*;
 pub use self::shaping::*;
@@ -16,7 +18,9 @@ pub use self::shift::*;
 
 use rustybuzz::Tag;
 use ttf_parser::Rect;
-use typst::font::{Font, FontStretch, FontStyle, FontWeight, VerticalFontMetric};
+use typst::font::{
+    Font, FontStretch, FontStyle, FontVariant, FontWeight, VerticalFontMetric,
+};
 
 use crate::layout::ParElem;
 use crate::prelude::*;
@@ -31,12 +35,14 @@ pub(super) fn define(global: &mut Scope) {
     global.define("lower", lower_func());
     global.define("upper", upper_func());
     global.define("smallcaps", smallcaps_func());
+    global.define("isolate", isolate_func());
     global.define("sub", SubElem::func());
     global.define("super", SuperElem::func());
     global.define("underline", UnderlineElem::func());
     global.define("strike", StrikeElem::func());
     global.define("overline", OverlineElem::func());
     global.define("raw", RawElem::func());
+    global.define("textpath", TextPathElem::func());
     global.define("lorem", lorem_func());
 }
 
@@ -59,6 +65,10 @@ pub(super) fn define(global: &mut Scope) {
 /// Display: Text
 /// Category: text
 #[element(Construct, PlainText)]
+#[scope(
+    scope.define("face", text_face_func());
+    scope
+)]
 pub struct TextElem {
     /// A prioritized sequence of font families.
     ///
@@ -80,15 +90,32 @@ pub struct TextElem {
     #[default(FontList(vec![FontFamily::new("Linux Libertine")]))]
     pub font: FontList,
 
+    /// Font families to use for specific scripts, tried before the
+    /// [`font`]($func/text.font) list.
+    ///
+    /// The keys are ISO 15924 script codes like `{"hani"}` for Chinese
+    /// characters, `{"arab"}` for Arabic or `{"latn"}` for Latin. Each run of
+    /// text in one of these scripts is set in the mapped families, even if an
+    /// earlier font in the regular list covers it as well. Other runs and
+    /// characters missing from the mapped families fall back to the regular
+    /// list as usual.
+    ///
+    /// ```example
+    /// #set text(
+    ///   font: "Noto Serif CJK JP",
+    ///   script-fonts: (hani: "Noto Serif CJK SC"),
+    /// )
+    ///
+    /// 汉字 and Latin
+    /// ```
+    pub script_fonts: ScriptFonts,
+
     /// Whether to allow last resort font fallback when the primary font list
     /// contains no match. This lets Typst search through all available fonts
     /// for the most similar one that has the necessary glyphs.
     ///
-    /// _Note:_ Currently, there are no warnings when fallback is disabled and
-    /// no glyphs are found. Instead, your text shows up in the form of "tofus":
-    /// Small boxes that indicate the lack of an appropriate glyph. In the
-    /// future, you will be able to instruct Typst to issue warnings so you know
-    /// something is up.
+    /// When fallback is disabled or no font has the necessary glyphs, the
+    /// [`missing`]($func/text.missing) parameter determines what happens.
     ///
     /// ```example
     /// #set text(font: "Inria Serif")
@@ -100,6 +127,25 @@ pub struct TextElem {
     #[default(true)]
     pub fallback: bool,
 
+    /// What to do with characters that no available font covers.
+    ///
+    /// - `{"tofu"}`: Show the primary font's placeholder glyph, usually a small
+    ///   box called a "tofu".
+    /// - `{"replace"}`: Show the replacement character `�` instead, if the
+    ///   font has it.
+    /// - `{"skip"}`: Leave the character out.
+    /// - `{"error"}`: Fail with an error pointing at the character.
+    ///
+    /// ```example
+    /// #set text(fallback: false)
+    /// هذا عربي
+    ///
+    /// #set text(missing: "replace")
+    /// هذا عربي
+    /// ```
+    #[default(MissingGlyphs::Tofu)]
+    pub missing: MissingGlyphs,
+
     /// The desired font style.
     ///
     /// When an italic style is requested and only an oblique one is available,
@@ -205,6 +251,24 @@ pub struct TextElem {
     #[default(Rel::one())]
     pub spacing: Rel<Length>,
 
+    /// The amount of space between sentences.
+    ///
+    /// This applies to spaces following a sentence-ending `.`, `!` or `?` and
+    /// is relative to the regular [word spacing]($func/text.spacing). By
+    /// default, sentences are separated like words. Spaces after
+    /// abbreviations are detected on a best-effort basis and left alone: A
+    /// period after a single capital letter (as in initials) or one that is
+    /// followed by a lowercase word does not end a sentence.
+    ///
+    /// ```example
+    /// #set text(sentence-spacing: 200%)
+    /// This is a sentence. And another
+    /// one! Written by J. Doe, e.g. me.
+    /// ```
+    #[resolve]
+    #[default(Rel::one())]
+    pub sentence_spacing: Rel<Length>,
+
     /// An amount to shift the text baseline by.
     ///
     /// ```example
@@ -368,6 +432,26 @@ pub struct TextElem {
     #[resolve]
     pub hyphenate: Hyphenate,
 
+    /// Words that are hyphenated differently than the language's hyphenation
+    /// patterns suggest.
+    ///
+    /// Each word is written with hyphens at the points where it may be broken,
+    /// e.g. `{"pro-ject"}`. A word without any hyphens is never hyphenated.
+    /// Words are matched regardless of case. The exceptions of multiple set
+    /// rules add up.
+    ///
+    /// ```example
+    /// #set page(width: 64pt)
+    /// #set par(justify: true)
+    /// #set text(
+    ///   hyphenate: true,
+    ///   hyphenation-exceptions: ("data-base",),
+    /// )
+    /// Our new database
+    /// ```
+    #[fold]
+    pub hyphenation_exceptions: Vec<EcoString>,
+
     /// Whether to apply kerning.
     ///
     /// When enabled, specific letter pairings move closer together or further
@@ -566,6 +650,61 @@ impl PlainText for TextElem {
     }
 }
 
+/// Finds out which font face is used for a font family and variant.
+///
+/// When a family lacks the requested style, weight, or stretch, Typst uses
+/// the closest variant the family has instead. Typst never synthesizes bold or
+/// italic faces. This function returns a dictionary describing the face that
+/// is actually used, with an `exact` key that is `{false}` if it differs from
+/// the requested variant. If the family is not available at all, it returns
+/// `{none}`.
+///
+/// ## Example { #example }
+/// ```example
+/// #let face = text.face(
+///   "Linux Libertine",
+///   style: "italic",
+///   weight: "black",
+/// )
+///
+/// Used weight: #face.weight \
+/// Exact match: #face.exact
+/// ```
+///
+/// Display: Font Face
+/// Category: text
+#[func]
+pub fn text_face(
+    /// The font family to look up.
+    family: FontFamily,
+    /// The requested font style.
+    #[named]
+    #[default]
+    style: FontStyle,
+    /// The requested font weight.
+    #[named]
+    #[default]
+    weight: FontWeight,
+    /// The requested font stretch.
+    #[named]
+    #[default]
+    stretch: FontStretch,
+    /// The virtual machine.
+    vm: &mut Vm,
+) -> Option<Dict> {
+    let variant = FontVariant::new(style, weight, stretch);
+    let world = vm.world();
+    let book = world.book();
+    let info = book.select(family.as_str(), variant).and_then(|id| book.info(id))?;
+    Some(dict! {
+        "family" => Str::from(info.family.as_str()),
+        "style" => info.variant.style,
+        "weight" => info.variant.weight,
+        "stretch" => info.variant.stretch,
+        "exact" => info.variant == variant,
+    })
+}
+
 /// A lowercased font family like "arial".
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct FontFamily(EcoString);
@@ -618,6 +757,32 @@ cast! {
     values: Array => Self(values.into_iter().map(|v| v.cast()).collect::<StrResult<_>>()?),
 }
 
+/// Font families for specific scripts.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ScriptFonts(pub Vec<(WritingScript, FontList)>);
+
+impl ScriptFonts {
+    /// The families mapped to the given script, if any.
+    pub fn get(&self, script: WritingScript) -> Option<&FontList> {
+        self.0.iter().find(|(s, _)| *s == script).map(|(_, list)| list)
+    }
+}
+
+cast! {
+    ScriptFonts,
+    self => self
+        .0
+        .into_iter()
+        .map(|(script, list)| (script.as_str().into(), list.into_value()))
+        .collect::<Dict>()
+        .into_value(),
+    dict: Dict => Self(
+        dict.into_iter()
+            .map(|(key, value)| Ok((key.parse()?, value.cast()?)))
+            .collect::<StrResult<_>>()?,
+    ),
+}
+
 /// The size of text.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TextSize(pub Length);
@@ -770,6 +935,19 @@ impl TryInto<VerticalFontMetric> for BottomEdgeMetric {
     }
 }
 
+/// What to do with characters that no available font covers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum MissingGlyphs {
+    /// Show the font's placeholder glyph.
+    Tofu,
+    /// Show the replacement character.
+    Replace,
+    /// Leave the character out.
+    Skip,
+    /// Fail with an error.
+    Error,
+}
+
 /// The direction of text and inline objects in their line.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct TextDir(pub Smart<Dir>);
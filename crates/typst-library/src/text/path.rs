@@ -0,0 +1,147 @@
+use super::{shape, TextElem};
+use crate::layout::SpanMapper;
+use crate::prelude::*;
+
+/// Sets text along a curved or slanted baseline.
+///
+/// Each glyph is placed on the baseline and rotated to follow its direction.
+/// Without a radius, the baseline is a straight line in the given direction.
+/// With a radius, it is an arc of a circle and the text is centered around the
+/// given angle, running clockwise. The resulting element is as large as the
+/// full circle or as the line, respectively, including the height of the text.
+///
+/// Only plain text is supported: Styling within the body is not retained, but
+/// the styles that apply to the text path itself are. To color the text, for
+/// example, use `{text(fill: red, textpath[..])}`.
+///
+/// ## Example { #example }
+/// ```example
+/// #textpath(radius: 1.5cm)[Certified Quality Seal]
+/// #textpath(angle: -20deg)[Upwards]
+/// ```
+///
+/// Display: Text Path
+/// Category: text
+#[element(Layout)]
+pub struct TextPathElem {
+    /// The radius of the circle the text follows. Must be positive.
+    ///
+    /// If this is `{none}`, the text follows a straight line instead.
+    pub radius: Option<Length>,
+
+    /// Where the text is placed.
+    ///
+    /// For a straight line, this is its direction, where `{0deg}` points to
+    /// the right. For a circle, it is the position of the text's center on
+    /// the circle, where `{0deg}` is at the top and positive angles go
+    /// clockwise.
+    pub angle: Angle,
+
+    /// The text to set along the path.
+    #[required]
+    pub body: Content,
+}
+
+impl Layout for TextPathElem {
+    #[tracing::instrument(name = "TextPathElem::layout", skip_all)]
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        _: Regions,
+    ) -> SourceResult<Fragment> {
+        let text = self.body().plain_text();
+        let dir = TextElem::dir_in(styles);
+        let lang = TextElem::lang_in(styles);
+        let region = TextElem::region_in(styles);
+        let mut spans = SpanMapper::new();
+        spans.push(text.len(), self.span());
+        let shaped = shape(vt, 0, &text, &spans, styles, dir, lang, region);
+        shaped.check_missing()?;
+        let line = shaped.build(vt, 0.0, Abs::zero());
+        let width = line.width();
+        let angle = self.angle(styles);
+        let top = line.baseline();
+        let bottom = line.height() - top;
+
+        let (mut frame, baseline) = match self.radius(styles) {
+            Some(radius) => {
+                let r = radius.resolve(styles);
+                if r <= Abs::zero() {
+                    bail!(self.span(), "radius must be positive");
+                }
+
+                // The text stands on the outside of the circle.
+                let size = Size::splat(2.0 * (r + top));
+                (
+                    Frame::new(size),
+                    Baseline::Arc { center: size.to_point() / 2.0, r, angle },
+                )
+            }
+            None => {
+                // Find the bounds of the text's rotated box, relative to the
+                // start of the baseline. The text's upwards direction is
+                // perpendicular to the line.
+                let (sin, cos) = (angle.sin(), angle.cos());
+                let along = Point::new(width * cos, width * sin);
+                let up = Point::new(Abs::raw(sin), Abs::raw(-cos));
+                let corners = [
+                    up * top.to_raw(),
+                    up * -bottom.to_raw(),
+                    along + up * top.to_raw(),
+                    along + up * -bottom.to_raw(),
+                ];
+                let min = corners.into_iter().reduce(Point::min).unwrap();
+                let max = corners.into_iter().reduce(Point::max).unwrap();
+                let start = -min;
+                (Frame::new((max - min).to_size()), Baseline::Line { start, angle })
+            }
+        };
+
+        for (pos, item) in line.items() {
+            let FrameItem::Text(text) = item else { continue };
+            let mut x = pos.x;
+            for glyph in &text.glyphs {
+                let advance = glyph.x_advance.at(text.size);
+                let (point, rotation) = baseline.place(x + advance / 2.0, width);
+                let single = TextItem { glyphs: vec![glyph.clone()], ..text.clone() };
+                let mut glyph_frame = Frame::new(Size::zero());
+                glyph_frame.push(
+                    Point::new(-advance / 2.0, pos.y - top),
+                    FrameItem::Text(single),
+                );
+                glyph_frame.transform(Transform::rotate(rotation));
+                frame.push_frame(point, glyph_frame);
+                x += advance;
+            }
+        }
+
+        Ok(Fragment::frame(frame))
+    }
+}
+
+/// The baseline along which a text path is set.
+enum Baseline {
+    /// A straight line from a start point in a direction.
+    Line { start: Point, angle: Angle },
+    /// An arc around a center point, centered at an angle from the top.
+    Arc { center: Point, r: Abs, angle: Angle },
+}
+
+impl Baseline {
+    /// The position and rotation of a glyph whose center is at the given
+    /// distance from the start of text with the given total width.
+    fn place(&self, distance: Abs, width: Abs) -> (Point, Angle) {
+        match *self {
+            Self::Line { start, angle } => {
+                let dir = Point::new(Abs::raw(angle.cos()), Abs::raw(angle.sin()));
+                (start + dir * distance.to_raw(), angle)
+            }
+            Self::Arc { center, r, angle } => {
+                let phi = angle + Angle::rad((distance - width / 2.0) / r);
+                let offset = Point::new(r * phi.sin(), -r * phi.cos());
+                (center + offset, phi)
+            }
+        }
+    }
+}
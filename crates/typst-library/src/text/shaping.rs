@@ -8,7 +8,7 @@ use typst::font::{Font, FontStyle, FontVariant};
 use typst::util::SliceExt;
 use unicode_script::{Script, UnicodeScript};
 
-use super::{decorate, FontFamily, NumberType, NumberWidth, TextElem};
+use super::{decorate, FontFamily, MissingGlyphs, NumberType, NumberWidth, TextElem};
 use crate::layout::SpanMapper;
 use crate::prelude::*;
 
@@ -214,6 +214,15 @@ enum Side {
 }
 
 impl<'a> ShapedText<'a> {
+    /// Fail if the text contains a character that no font covers and
+    /// [missing glyphs](MissingGlyphs::Error) are configured to be an error.
+    pub fn check_missing(&self) -> SourceResult<()> {
+        match self.glyphs.iter().find(|glyph| glyph.glyph_id == 0) {
+            Some(glyph) => missing_glyph(self.styles, glyph.c, glyph.span.0),
+            None => Ok(()),
+        }
+    }
+
     /// Build the shaped text's frame.
     ///
     /// The `justification` defines how much extra advance width each
@@ -565,10 +574,11 @@ pub fn shape<'a>(
     };
 
     if !text.is_empty() {
-        shape_segment(&mut ctx, base, text, families(styles));
+        let families = script_families(text, styles).into_iter().chain(families(styles));
+        shape_segment(&mut ctx, base, text, families);
     }
 
-    track_and_space(&mut ctx);
+    track_and_space(&mut ctx, base, text);
     calculate_adjustability(&mut ctx, lang, region);
 
     #[cfg(debug_assertions)]
@@ -590,6 +600,41 @@ pub fn shape<'a>(
     }
 }
 
+/// Handle a character that no font covers.
+///
+/// Fails if [missing glyphs](MissingGlyphs::Error) are configured to be an
+/// error. Otherwise, the placeholder chosen during shaping stays in place.
+pub fn missing_glyph(styles: StyleChain, c: char, span: Span) -> SourceResult<()> {
+    if TextElem::missing_in(styles) == MissingGlyphs::Error {
+        bail!(span, "current font does not support the character {:?}", c);
+    }
+    Ok(())
+}
+
+/// Measure a run of text without building a frame for it.
+///
+/// The text is shaped in the direction and language given by the styles, but
+/// not broken into lines. The width is the sum of the glyph advances,
+/// including tracking and spacing, while the height spans from the text's
+/// [top edge](TextElem::top_edge) to its [bottom edge](TextElem::bottom_edge).
+/// Errors for missing glyphs point to the given span.
+pub fn measure_text(
+    vt: &Vt,
+    text: &str,
+    span: Span,
+    styles: StyleChain,
+) -> SourceResult<Size> {
+    let dir = TextElem::dir_in(styles);
+    let lang = TextElem::lang_in(styles);
+    let region = TextElem::region_in(styles);
+    let mut spans = SpanMapper::new();
+    spans.push(text.len(), span);
+    let shaped = shape(vt, 0, text, &spans, styles, dir, lang, region);
+    shaped.check_missing()?;
+    let (top, bottom) = shaped.measure(vt);
+    Ok(Size::new(shaped.width, top + bottom))
+}
+
 /// Shape text with font fallback using the `families` iterator.
 fn shape_segment(
     ctx: &mut ShapingContext,
@@ -728,14 +773,28 @@ fn shape_segment(
 }
 
 /// Shape the text with tofus from the given font.
+///
+/// Depending on the [`MissingGlyphs`] setting, the tofus are the font's
+/// placeholder glyph, its replacement character, or invisible.
 fn shape_tofus(ctx: &mut ShapingContext, base: usize, text: &str, font: Font) {
-    let x_advance = font.advance(0).unwrap_or_default();
+    let glyph = |c| font.ttf().glyph_index(c).map(|id| id.0);
+    let advance = |id| font.advance(id).unwrap_or_default();
+    let (glyph_id, x_advance) = match TextElem::missing_in(ctx.styles) {
+        MissingGlyphs::Tofu | MissingGlyphs::Error => (0, advance(0)),
+        MissingGlyphs::Replace => {
+            let id = glyph('\u{FFFD}').unwrap_or(0);
+            (id, advance(id))
+        }
+        // The space glyph has no outline, so the character vanishes.
+        MissingGlyphs::Skip => (glyph(' ').unwrap_or(0), Em::zero()),
+    };
+
     let add_glyph = |(cluster, c): (usize, char)| {
         let start = base + cluster;
         let end = start + c.len_utf8();
         ctx.glyphs.push(ShapedGlyph {
             font: font.clone(),
-            glyph_id: 0,
+            glyph_id,
             x_advance,
             x_offset: Em::zero(),
             y_offset: Em::zero(),
@@ -754,10 +813,12 @@ fn shape_tofus(ctx: &mut ShapingContext, base: usize, text: &str, font: Font) {
 }
 
 /// Apply tracking and spacing to the shaped glyphs.
-fn track_and_space(ctx: &mut ShapingContext) {
+fn track_and_space(ctx: &mut ShapingContext, base: usize, text: &str) {
     let tracking = Em::from_length(TextElem::tracking_in(ctx.styles), ctx.size);
     let spacing =
         TextElem::spacing_in(ctx.styles).map(|abs| Em::from_length(abs, ctx.size));
+    let sentence_spacing = TextElem::sentence_spacing_in(ctx.styles)
+        .map(|abs| Em::from_length(abs, ctx.size));
 
     let mut glyphs = ctx.glyphs.iter_mut().peekable();
     while let Some(glyph) = glyphs.next() {
@@ -768,6 +829,12 @@ fn track_and_space(ctx: &mut ShapingContext) {
 
         if glyph.is_space() {
             glyph.x_advance = spacing.relative_to(glyph.x_advance);
+            if sentence_spacing != Rel::one()
+                && glyph.c == ' '
+                && ends_sentence(text, glyph.range.start - base)
+            {
+                glyph.x_advance = sentence_spacing.relative_to(glyph.x_advance);
+            }
         }
 
         if glyphs
@@ -779,6 +846,36 @@ fn track_and_space(ctx: &mut ShapingContext) {
     }
 }
 
+/// Whether the space at the given index in the text follows the end of a
+/// sentence.
+///
+/// This is a heuristic: A `.` after a single capital letter is most likely an
+/// initial and a lowercase word after the space most likely continues the
+/// sentence after an abbreviation.
+fn ends_sentence(text: &str, index: usize) -> bool {
+    let mut before = text[..index].chars().rev();
+    let mut after = text[index..].chars().skip(1);
+    let punct = before.next();
+    if !matches!(punct, Some('.' | '!' | '?')) {
+        return false;
+    }
+
+    if after.next().map_or(false, char::is_lowercase) {
+        return false;
+    }
+
+    if punct == Some('.') {
+        let mut word = before.take_while(|c| c.is_alphabetic());
+        if let (Some(c), None) = (word.next(), word.next()) {
+            if c.is_uppercase() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 pub fn is_gb_style(lang: Lang, region: Option<Region>) -> bool {
     // Most CJK variants, including zh-CN, ja-JP, zh-SG, zh-MY use GB-style punctuation,
     // while zh-HK and zh-TW use alternative style. We default to use GB-style.
@@ -866,6 +963,32 @@ pub fn families(styles: StyleChain) -> impl Iterator<Item = FontFamily> + Clone
         .chain(tail.iter().copied().map(FontFamily::new))
 }
 
+/// The families that the script of the text is mapped to through
+/// [`TextElem::script_fonts`].
+///
+/// Paragraphs are shaped in runs of a single script, so the first character
+/// with a specific script determines it, unless the script is set explicitly.
+fn script_families(text: &str, styles: StyleChain) -> Vec<FontFamily> {
+    let fonts = TextElem::script_fonts_in(styles);
+    if fonts.0.is_empty() {
+        return vec![];
+    }
+
+    let script = match TextElem::script_in(styles) {
+        Smart::Custom(script) => Some(script),
+        Smart::Auto => text
+            .chars()
+            .map(|c| c.script())
+            .find(|s| !matches!(s, Script::Unknown | Script::Common | Script::Inherited))
+            .and_then(|s| s.short_name().parse().ok()),
+    };
+
+    script
+        .and_then(|script| fonts.get(script))
+        .map(|list| list.0.clone())
+        .unwrap_or_default()
+}
+
 /// Collect the tags of the OpenType features to apply.
 pub fn tags(styles: StyleChain) -> Vec<Feature> {
     let mut tags = vec![];
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+/// Composites content as a whole with an opacity.
+///
+/// Transparent colors apply to each shape or text run separately, so where
+/// two translucent shapes overlap, the overlapping area appears darker. A
+/// group first draws its contents opaquely and then blends the result with the
+/// given opacity, making overlapping parts look like a single layer.
+///
+/// The contents of a group are clipped to its bounds.
+///
+/// ## Example { #example }
+/// ```example
+/// #let shapes = stack(
+///   dir: ltr,
+///   spacing: -10pt,
+///   circle(fill: blue),
+///   circle(fill: blue),
+/// )
+///
+/// #group(opacity: 50%, shapes)
+/// ```
+///
+/// Display: Group
+/// Category: visualize
+#[element(Layout)]
+pub struct GroupElem {
+    /// The opacity with which the contents are composited.
+    ///
+    /// Must be between `{0%}` and `{100%}`.
+    #[default(Ratio::one())]
+    pub opacity: Ratio,
+
+    /// The content to composite.
+    #[required]
+    pub body: Content,
+}
+
+impl Layout for GroupElem {
+    #[tracing::instrument(name = "GroupElem::layout", skip_all)]
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let opacity = self.opacity(styles);
+        if !(0.0..=1.0).contains(&opacity.get()) {
+            bail!(self.span(), "opacity must be between 0% and 100%");
+        }
+
+        let pod = Regions::one(regions.base(), Axes::splat(false));
+        let mut frame = self.body().layout(vt, styles, pod)?.into_frame();
+        frame.set_opacity(opacity);
+        Ok(Fragment::frame(frame))
+    }
+}
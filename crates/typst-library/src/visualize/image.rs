@@ -11,7 +11,10 @@ use crate::text::families;
 
 /// A raster or vector graphic.
 ///
-/// Supported formats are PNG, JPEG, GIF and SVG.
+/// Supported formats are PNG, JPEG, GIF, SVG and PDF. Of a PDF file, a single
+/// page is embedded. This works for files with a classic cross-reference table
+/// and appears only in PDF export; PNG and SVG exports show a gray placeholder
+/// instead.
 ///
 /// _Note:_ Work on SVG export is ongoing and there might be visual inaccuracies
 /// in the resulting PDF. Make sure to double-check embedded SVG images. If you
@@ -70,6 +73,10 @@ pub struct ImageElem {
     /// How the image should adjust itself to a given area.
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
+
+    /// Which page to embed if the image is a PDF file.
+    #[default(NonZeroUsize::ONE)]
+    pub page: NonZeroUsize,
 }
 
 /// Decode a raster of vector graphic from bytes or a string.
@@ -107,6 +114,9 @@ pub fn image_decode(
     /// How the image should adjust itself to a given area.
     #[named]
     fit: Option<ImageFit>,
+    /// Which page to embed if the image is a PDF file.
+    #[named]
+    page: Option<NonZeroUsize>,
 ) -> StrResult<Content> {
     let mut elem = ImageElem::new(EcoString::new(), data);
     if let Some(format) = format {
@@ -124,6 +134,9 @@ pub fn image_decode(
     if let Some(fit) = fit {
         elem.push_fit(fit);
     }
+    if let Some(page) = page {
+        elem.push_page(page);
+    }
     Ok(elem.pack())
 }
 
@@ -152,8 +165,12 @@ impl Layout for ImageElem {
                     "jpg" | "jpeg" => ImageFormat::Raster(RasterFormat::Jpg),
                     "gif" => ImageFormat::Raster(RasterFormat::Gif),
                     "svg" | "svgz" => ImageFormat::Vector(VectorFormat::Svg),
+                    "pdf" => ImageFormat::Vector(VectorFormat::Pdf),
                     _ => match &data {
                         Readable::Str(_) => ImageFormat::Vector(VectorFormat::Svg),
+                        Readable::Bytes(bytes) if bytes.starts_with(b"%PDF-") => {
+                            ImageFormat::Vector(VectorFormat::Pdf)
+                        }
                         Readable::Bytes(bytes) => match RasterFormat::detect(bytes) {
                             Some(f) => ImageFormat::Raster(f),
                             None => bail!(self.span(), "unknown image format"),
@@ -163,13 +180,17 @@ impl Layout for ImageElem {
             }
         };
 
-        let image = Image::with_fonts(
-            data.into(),
-            format,
-            vt.world,
-            families(styles).next().as_ref().map(|f| f.as_str()),
-            self.alt(styles),
-        )
+        let image = if format == ImageFormat::Vector(VectorFormat::Pdf) {
+            Image::pdf(data.into(), self.page(styles), self.alt(styles))
+        } else {
+            Image::with_fonts(
+                data.into(),
+                format,
+                vt.world,
+                families(styles).next().as_ref().map(|f| f.as_str()),
+                self.alt(styles),
+            )
+        }
         .at(self.span())?;
 
         let sizing = Axes::new(self.width(styles), self.height(styles));
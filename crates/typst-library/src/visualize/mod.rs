@@ -1,14 +1,18 @@
 //! Drawing and visualization.
 
+mod group;
 mod image;
 mod line;
 mod path;
+mod pattern;
 mod polygon;
 mod shape;
 
+pub use self::group::*;
 pub use self::image::*;
 pub use self::line::*;
 pub use self::path::*;
+pub use self::pattern::*;
 pub use self::polygon::*;
 pub use self::shape::*;
 
@@ -24,6 +28,8 @@ pub(super) fn define(global: &mut Scope) {
     global.define("circle", CircleElem::func());
     global.define("polygon", PolygonElem::func());
     global.define("path", PathElem::func());
+    global.define("pattern", pattern_func());
+    global.define("group", GroupElem::func());
     global.define("black", Color::BLACK);
     global.define("gray", Color::GRAY);
     global.define("silver", Color::SILVER);
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+
+/// Create a pattern that repeats a tile of content.
+///
+/// A pattern can be used wherever a color is expected as a fill or stroke
+/// paint. Its tiles are laid out in a grid that starts at the top-left corner
+/// of the filled shape or text run.
+///
+/// The tile's body is laid out with the default styles, independently of
+/// where the pattern is used.
+///
+/// ## Example { #example }
+/// ```example
+/// #let hatched = pattern(size: (10pt, 10pt))[
+///   #place(line(start: (0%, 100%), end: (100%, 0%)))
+/// ]
+///
+/// #rect(width: 100%, height: 40pt, fill: hatched)
+/// #rect(
+///   width: 100%,
+///   height: 40pt,
+///   fill: pattern(spacing: (4pt, 4pt))[*Hi!*],
+/// )
+/// ```
+///
+/// Display: Pattern
+/// Category: visualize
+#[func]
+pub fn pattern(
+    /// The size of a single tile. By default, the tile is as large as its
+    /// laid-out body. Both components must be absolute lengths.
+    #[named]
+    #[default]
+    size: Smart<Axes<Rel<Length>>>,
+    /// The gap between adjacent tiles, horizontally and vertically. Both
+    /// components must be absolute lengths.
+    #[named]
+    #[default]
+    spacing: Axes<Rel<Length>>,
+    /// The content of a single tile.
+    body: Content,
+    /// The virtual machine.
+    vm: &mut Vm,
+    /// The callsite span.
+    span: Span,
+) -> SourceResult<Pattern> {
+    let world = vm.world();
+    let styles = StyleChain::new(&world.library().styles);
+    let size = match size {
+        Smart::Auto => Smart::Auto,
+        Smart::Custom(size) => Smart::Custom(absolute(size).at(span)?),
+    };
+    let spacing = absolute(spacing).at(span)?;
+
+    let pod = match size {
+        Smart::Auto => Regions::one(Axes::splat(Abs::inf()), Axes::splat(false)),
+        Smart::Custom(size) => Regions::one(size.resolve(styles), Axes::splat(true)),
+    };
+
+    let mut frame = body.measure(&mut vm.vt, styles, pod)?.into_frame();
+    if let Smart::Custom(size) = size {
+        frame.set_size(size.resolve(styles));
+    }
+
+    Pattern::new(frame, spacing.resolve(styles)).at(span)
+}
+
+/// Extract the lengths from a tile size or spacing, as there is no region for
+/// relative parts to refer to.
+fn absolute(axes: Axes<Rel<Length>>) -> StrResult<Axes<Length>> {
+    if !axes.x.rel.is_zero() || !axes.y.rel.is_zero() {
+        bail!("pattern dimensions must be absolute lengths");
+    }
+    Ok(axes.map(|v| v.abs))
+}
@@ -689,6 +689,14 @@ node! {
 }
 
 impl ListItem {
+    /// Whether the item is a checked task (`- [x]`), an unchecked task
+    /// (`- [ ]`), or no task at all.
+    pub fn checked(&self) -> Option<bool> {
+        let checkbox =
+            self.0.children().find(|node| node.kind() == SyntaxKind::Checkbox)?;
+        Some(checkbox.children().any(|node| node.kind() == SyntaxKind::Text))
+    }
+
     /// The contents of the list item.
     pub fn body(&self) -> Markup {
         self.0.cast_first_match().unwrap_or_default()
@@ -2025,3 +2033,31 @@ impl FuncReturn {
         self.0.cast_last_match()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_list_item_checked() {
+        let checked = |text: &str| {
+            let root = parse(text);
+            let markup = root.cast::<Markup>().unwrap();
+            let item = markup.exprs().find_map(|expr| match expr {
+                Expr::List(item) => Some(item),
+                _ => None,
+            });
+            item.unwrap().checked()
+        };
+
+        assert_eq!(checked("- [ ] Todo"), Some(false));
+        assert_eq!(checked("- [x] Done"), Some(true));
+        assert_eq!(checked("- [X] Done"), Some(true));
+        assert_eq!(checked("- [x]"), Some(true));
+        assert_eq!(checked("- Plain"), None);
+        assert_eq!(checked("- [] Empty"), None);
+        assert_eq!(checked("- [y] Other"), None);
+        assert_eq!(checked("- [x]Attached"), None);
+    }
+}
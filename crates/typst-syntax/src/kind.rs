@@ -44,6 +44,8 @@ pub enum SyntaxKind {
     ListItem,
     /// Introduces a list item: `-`.
     ListMarker,
+    /// Marks a list item as a task: `[ ]` or `[x]`.
+    Checkbox,
     /// An item in an enumeration (numbered list): `+ ...` or `1. ...`.
     EnumItem,
     /// Introduces an enumeration item: `+`, `1.`.
@@ -370,6 +372,7 @@ impl SyntaxKind {
             Self::HeadingMarker => "heading marker",
             Self::ListItem => "list item",
             Self::ListMarker => "list marker",
+            Self::Checkbox => "checkbox",
             Self::EnumItem => "enum item",
             Self::EnumMarker => "enum marker",
             Self::TermItem => "term list item",
@@ -15,7 +15,7 @@ pub use self::file::{FileId, PackageSpec, PackageVersion};
 pub use self::kind::SyntaxKind;
 pub use self::lexer::{is_id_continue, is_id_start, is_ident, is_newline};
 pub use self::node::{LinkedChildren, LinkedNode, SyntaxError, SyntaxNode};
-pub use self::parser::{parse, parse_code, parse_math};
+pub use self::parser::{parse, parse_code, parse_math, tokenize, Token};
 pub use self::source::Source;
 pub use self::span::{Span, Spanned};
 
@@ -876,7 +876,7 @@ impl std::error::Error for Unnumberable {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Source;
+    use crate::{Source, Token};
 
     #[test]
     fn test_linked_node() {
@@ -909,4 +909,40 @@ mod tests {
         assert_eq!(leaf.text(), " ");
         assert_eq!(next.text(), "10");
     }
+
+    #[test]
+    fn test_tokenize() {
+        let text = "= Hi // Note\n#let x = $a^2$ \n";
+        let tokens = crate::tokenize(text);
+        let heading = Token { kind: SyntaxKind::HeadingMarker, range: 0..1 };
+        let comment = Token { kind: SyntaxKind::LineComment, range: 5..12 };
+        assert_eq!(tokens.first(), Some(&heading));
+        assert!(tokens.contains(&comment));
+        assert_eq!(comment.text(text), "// Note");
+        assert!(tokens.windows(2).all(|w| w[0].range.end == w[1].range.start));
+        assert_eq!(tokens.last().map(|token| token.range.end), Some(text.len()));
+    }
+
+    #[test]
+    fn test_tokenize_snapshot() {
+        let text = "*Hi* #f(1)";
+        let tokens: Vec<_> = crate::tokenize(text)
+            .iter()
+            .map(|token| (token.kind.name(), token.text(text)))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("star", "*"),
+                ("text", "Hi"),
+                ("star", "*"),
+                ("space", " "),
+                ("hashtag", "#"),
+                ("identifier", "f"),
+                ("opening paren", "("),
+                ("integer", "1"),
+                ("closing paren", ")"),
+            ]
+        );
+    }
 }
@@ -33,6 +33,51 @@ pub fn parse_math(text: &str) -> SyntaxNode {
     p.finish().into_iter().next().unwrap()
 }
 
+/// A token in a source file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Token {
+    /// What kind of token this is.
+    pub kind: SyntaxKind,
+    /// The byte range of the token in the source text.
+    pub range: Range<usize>,
+}
+
+impl Token {
+    /// The token's text within the source text it was produced from.
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.range.clone()]
+    }
+}
+
+/// Split a source file into its tokens.
+///
+/// Which tokens the text consists of depends on whether it is in markup, code
+/// or math, so the text is parsed first. The tokens are the leaves of the
+/// resulting syntax tree, in order. They include whitespace, comments and
+/// errors and cover the whole text without gaps, so the output is
+/// deterministic and suitable for snapshot tests.
+pub fn tokenize(text: &str) -> Vec<Token> {
+    fn leaves(node: &SyntaxNode, offset: usize, tokens: &mut Vec<Token>) {
+        if node.children().len() == 0 {
+            if node.len() > 0 {
+                let range = offset..offset + node.len();
+                tokens.push(Token { kind: node.kind(), range });
+            }
+            return;
+        }
+
+        let mut offset = offset;
+        for child in node.children() {
+            leaves(child, offset, tokens);
+            offset += child.len();
+        }
+    }
+
+    let mut tokens = vec![];
+    leaves(&parse(text), 0, &mut tokens);
+    tokens
+}
+
 fn markup(
     p: &mut Parser,
     mut at_start: bool,
@@ -182,10 +227,34 @@ fn list_item(p: &mut Parser) {
     let min_indent = p.column(p.current_start()) + 1;
     p.assert(SyntaxKind::ListMarker);
     whitespace_line(p);
+    if at_checkbox(p) {
+        let c = p.marker();
+        for _ in 0..3 {
+            p.eat();
+        }
+        p.wrap(c, SyntaxKind::Checkbox);
+        whitespace_line(p);
+    }
     markup(p, false, min_indent, |p| p.at(SyntaxKind::RightBracket));
     p.wrap(m, SyntaxKind::ListItem);
 }
 
+/// Whether the parser is at the checkbox of a task list item: `[ ]`, `[x]`
+/// or `[X]`, followed by a space.
+fn at_checkbox(p: &Parser) -> bool {
+    if !p.at(SyntaxKind::LeftBracket) {
+        return false;
+    }
+
+    let mut lexer = p.lexer.clone();
+    let start = lexer.cursor();
+    let inner = lexer.next();
+    let text = &p.text[start..lexer.cursor()];
+    matches!((inner, text), (SyntaxKind::Space, " ") | (SyntaxKind::Text, "x" | "X"))
+        && lexer.next() == SyntaxKind::RightBracket
+        && matches!(lexer.next(), SyntaxKind::Space | SyntaxKind::Eof)
+}
+
 fn enum_item(p: &mut Parser) {
     let m = p.marker();
     let min_indent = p.column(p.current_start()) + 1;
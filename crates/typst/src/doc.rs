@@ -7,12 +7,15 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use ecow::EcoString;
+use ttf_parser::GlyphId;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::eval::{cast, dict, Dict, Value};
+use crate::eval::{cast, dict, Datetime, Dict, Value};
 use crate::font::Font;
 use crate::geom::{
     self, rounded_rect, Abs, Align, Axes, Color, Corners, Dir, Em, Geometry, Length,
-    Numeric, Paint, Point, Rel, RgbaColor, Shape, Sides, Size, Stroke, Transform,
+    Numeric, Paint, Path, Point, Ratio, Rel, RgbaColor, Shape, Sides, Size, Stroke,
+    Transform,
 };
 use crate::image::Image;
 use crate::model::{Content, Location, MetaElem, StyleChain};
@@ -27,6 +30,89 @@ pub struct Document {
     pub title: Option<EcoString>,
     /// The document's author.
     pub author: Vec<EcoString>,
+    /// The document's subject.
+    pub subject: Option<EcoString>,
+    /// The document's keywords.
+    pub keywords: Vec<EcoString>,
+    /// The document's creation date.
+    pub date: Option<Datetime>,
+}
+
+impl Document {
+    /// Append the pages of another document to this one.
+    ///
+    /// This allows to typeset parts of a document separately and export them
+    /// together. Fonts and images shared between the parts are deduplicated
+    /// during export. The pages are not laid out again, so page numbers that
+    /// should continue across parts must be set up front, e.g. with
+    /// `{counter(page).update(n)}` at the start of each part. The title,
+    /// subject and date are kept if there are any and new authors and
+    /// keywords are added.
+    pub fn append(&mut self, other: Document) {
+        self.pages.extend(other.pages);
+        self.title = self.title.take().or(other.title);
+        self.subject = self.subject.take().or(other.subject);
+        self.date = self.date.or(other.date);
+        for author in other.author {
+            if !self.author.contains(&author) {
+                self.author.push(author);
+            }
+        }
+        for keyword in other.keywords {
+            if !self.keywords.contains(&keyword) {
+                self.keywords.push(keyword);
+            }
+        }
+    }
+
+    /// Compute statistics about the document.
+    ///
+    /// The text statistics are based on the text that ended up on the pages.
+    /// Words that are hyphenated across lines are thus counted twice.
+    pub fn stats(&self) -> DocumentStats {
+        let mut text = EcoString::new();
+        for page in &self.pages {
+            page.collect_text(&mut text);
+        }
+        DocumentStats {
+            pages: self.pages.len(),
+            text: TextStats::of(&text),
+        }
+    }
+}
+
+/// Statistics about a finished document.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DocumentStats {
+    /// The number of pages.
+    pub pages: usize,
+    /// Statistics about the text on the pages.
+    pub text: TextStats,
+}
+
+/// Statistics about a piece of text.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TextStats {
+    /// The number of words.
+    pub words: usize,
+    /// The number of characters, not counting whitespace.
+    pub chars: usize,
+}
+
+impl TextStats {
+    /// Compute statistics for a string.
+    ///
+    /// Words are determined by the Unicode word boundary rules. In
+    /// space-delimited scripts, a word is thus a run of letters and digits,
+    /// while in scripts without word separators like Chinese or Japanese,
+    /// each ideograph counts as a word. Characters are counted as grapheme
+    /// clusters.
+    pub fn of(text: &str) -> Self {
+        Self {
+            words: text.unicode_words().count(),
+            chars: text.graphemes(true).filter(|g| !g.trim().is_empty()).count(),
+        }
+    }
 }
 
 /// A finished layout with items at fixed positions.
@@ -320,6 +406,20 @@ impl Frame {
         }
     }
 
+    /// Composite the contents of the frame as a whole with the given opacity.
+    ///
+    /// Unlike translucent paints, overlapping items within the frame don't
+    /// blend with each other. The contents are also clipped to the frame's
+    /// size because PDF transparency groups are bounded.
+    pub fn set_opacity(&mut self, opacity: Ratio) {
+        if !self.is_empty() && opacity != Ratio::one() {
+            self.group(|g| {
+                g.clips = true;
+                g.opacity = opacity;
+            });
+        }
+    }
+
     /// Wrap the frame's contents in a group and modify that group with `f`.
     fn group<F>(&mut self, f: F)
     where
@@ -334,6 +434,114 @@ impl Frame {
     }
 }
 
+/// Inspecting the contents.
+impl Frame {
+    /// Append the plain text of all text items in this frame and its
+    /// subframes to `text`.
+    ///
+    /// Items that directly follow each other on the same baseline, like the
+    /// parts of a word with differently styled letters, are joined as they
+    /// are. Between all others, for example at line breaks, a space is
+    /// inserted unless there already is one.
+    pub fn collect_text(&self, text: &mut EcoString) {
+        self.collect_text_impl(text, Transform::identity(), &mut None);
+    }
+
+    /// Collect the text with the transform from this frame to the outermost
+    /// one and the end of the last text item in the outermost frame.
+    fn collect_text_impl(
+        &self,
+        text: &mut EcoString,
+        ts: Transform,
+        last: &mut Option<Point>,
+    ) {
+        for &(pos, ref item) in self.items() {
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts
+                        .pre_concat(Transform::translate(pos.x, pos.y))
+                        .pre_concat(group.transform);
+                    group.frame.collect_text_impl(text, ts, last);
+                }
+                FrameItem::Text(item) => {
+                    let start = pos.transform(ts);
+                    let adjacent = last.map_or(false, |end| {
+                        end.x.approx_eq(start.x) && end.y.approx_eq(start.y)
+                    });
+                    let spaced = text.ends_with(char::is_whitespace)
+                        || item.text.starts_with(char::is_whitespace);
+                    if !text.is_empty() && !adjacent && !spaced {
+                        text.push(' ');
+                    }
+                    text.push_str(&item.text);
+                    *last = Some((pos + Point::with_x(item.width())).transform(ts));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// The bounding box of everything that is drawn in this frame and its
+    /// subframes, as its top-left corner and its size.
+    ///
+    /// Unlike the frame's size, this tightly encloses the outlines of glyphs
+    /// instead of their advances and the font's ascender and descender. The
+    /// strokes of shapes are not included. Returns `None` if nothing is drawn.
+    pub fn ink_bbox(&self) -> Option<(Point, Size)> {
+        let mut min = Point::new(Abs::inf(), Abs::inf());
+        let mut max = Point::new(-Abs::inf(), -Abs::inf());
+        let mut extend = |point: Point| {
+            min = min.min(point);
+            max = max.max(point);
+        };
+
+        for &(pos, ref item) in self.items() {
+            match item {
+                FrameItem::Group(group) => {
+                    let Some((origin, size)) = group.frame.ink_bbox() else { continue };
+                    for corner in [
+                        origin,
+                        origin + Point::with_x(size.x),
+                        origin + Point::with_y(size.y),
+                        origin + size.to_point(),
+                    ] {
+                        extend(pos + corner.transform(group.transform));
+                    }
+                }
+                FrameItem::Text(text) => {
+                    let at = |units: i16| text.font.to_em(units).at(text.size);
+                    let mut x = Abs::zero();
+                    for glyph in &text.glyphs {
+                        let id = GlyphId(glyph.id);
+                        if let Some(rect) = text.font.ttf().glyph_bounding_box(id) {
+                            let x = x + glyph.x_offset.at(text.size);
+                            extend(pos + Point::new(x + at(rect.x_min), -at(rect.y_max)));
+                            extend(pos + Point::new(x + at(rect.x_max), -at(rect.y_min)));
+                        }
+                        x += glyph.x_advance.at(text.size);
+                    }
+                }
+                FrameItem::Shape(shape, _) => {
+                    extend(pos);
+                    extend(
+                        pos + match shape.geometry {
+                            Geometry::Line(target) => target,
+                            ref geometry => geometry.bbox_size().to_point(),
+                        },
+                    );
+                }
+                FrameItem::Image(_, size, _) => {
+                    extend(pos);
+                    extend(pos + size.to_point());
+                }
+                FrameItem::Meta(..) => {}
+            }
+        }
+
+        (min.x <= max.x).then(|| (min, (max - min).to_size()))
+    }
+}
+
 /// Tools for debugging.
 impl Frame {
     /// Add a full size aqua background and a red baseline for debugging.
@@ -440,6 +648,8 @@ pub struct GroupItem {
     pub transform: Transform,
     /// Whether the frame should be a clipping boundary.
     pub clips: bool,
+    /// The opacity with which the group is composited as a whole.
+    pub opacity: Ratio,
 }
 
 impl GroupItem {
@@ -449,6 +659,7 @@ impl GroupItem {
             frame,
             transform: Transform::identity(),
             clips: false,
+            opacity: Ratio::one(),
         }
     }
 }
@@ -482,6 +693,34 @@ impl TextItem {
     pub fn width(&self) -> Abs {
         self.glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(self.size)
     }
+
+    /// The bounding box of the text run relative to the start of its
+    /// baseline, as its top-left corner and its size.
+    ///
+    /// Vertically, the box spans from the font's ascender to its descender.
+    pub fn bbox(&self) -> (Point, Size) {
+        let metrics = self.font.metrics();
+        let top = metrics.ascender.at(self.size);
+        let bottom = metrics.descender.at(self.size);
+        (Point::with_y(-top), Size::new(self.width(), top - bottom))
+    }
+
+    /// The outlines of the glyphs, each with its position relative to the
+    /// start of the baseline.
+    ///
+    /// Glyphs without an outline, like spaces, are skipped.
+    pub fn outlines(&self) -> Vec<(Point, Path)> {
+        let mut x = Abs::zero();
+        let mut outlines = vec![];
+        for glyph in &self.glyphs {
+            let pos = Point::with_x(x + glyph.x_offset.at(self.size));
+            if let Some(path) = self.font.outline(glyph.id, self.size) {
+                outlines.push((pos, path));
+            }
+            x += glyph.x_advance.at(self.size);
+        }
+        outlines
+    }
 }
 
 impl Debug for TextItem {
@@ -672,6 +911,8 @@ pub enum Meta {
     /// in the final frames as it is removed alongside the content that should
     /// be hidden.
     Hide,
+    /// Marks the baseline of a line of text for line numbering.
+    Line,
 }
 
 cast! {
@@ -685,6 +926,7 @@ impl Debug for Meta {
             Self::Elem(content) => write!(f, "Elem({:?})", content.func()),
             Self::PageNumbering(value) => write!(f, "PageNumbering({value:?})"),
             Self::Hide => f.pad("Hide"),
+            Self::Line => f.pad("Line"),
         }
     }
 }
@@ -755,6 +997,45 @@ mod tests {
         assert!(!option_eq(region, "AB"));
     }
 
+    #[test]
+    fn test_text_stats() {
+        let stats = |text| {
+            let TextStats { words, chars } = TextStats::of(text);
+            (words, chars)
+        };
+        assert_eq!(stats(""), (0, 0));
+        assert_eq!(stats("Hello, world!"), (2, 12));
+        assert_eq!(stats("  it's  a test "), (3, 9));
+        assert_eq!(stats("你好世界"), (4, 4));
+    }
+
+    #[test]
+    fn test_document_append() {
+        let part = |pages, title: Option<&str>, author: &[&str]| Document {
+            pages: vec![Frame::new(Size::zero()); pages],
+            title: title.map(Into::into),
+            author: author.iter().map(|&a| a.into()).collect(),
+            ..Document::default()
+        };
+        let mut document = part(2, None, &["A"]);
+        document.append(part(3, Some("Book"), &["A", "B"]));
+        document.append(Document {
+            keywords: vec!["x".into(), "y".into()],
+            date: Datetime::from_ymd(2023, 9, 4),
+            ..part(1, Some("Other"), &["C"])
+        });
+        document.append(Document {
+            keywords: vec!["y".into(), "z".into()],
+            date: Datetime::from_ymd(2024, 1, 1),
+            ..Document::default()
+        });
+        assert_eq!(document.pages.len(), 6);
+        assert_eq!(document.title.as_deref(), Some("Book"));
+        assert_eq!(document.author, ["A", "B", "C"].map(EcoString::from));
+        assert_eq!(document.keywords, ["x", "y", "z"].map(EcoString::from));
+        assert_eq!(document.date, Datetime::from_ymd(2023, 9, 4));
+    }
+
     #[test]
     fn test_document_is_send() {
         fn ensure_send<T: Send>() {}
@@ -72,7 +72,7 @@ pub struct LangItems {
     /// The heading function.
     pub heading_func: ElemFunc,
     /// An item in a bullet list: `- ...`.
-    pub list_item: fn(body: Content) -> Content,
+    pub list_item: fn(checked: Option<bool>, body: Content) -> Content,
     /// An item in an enumeration (numbered list): `+ ...` or `1. ...`.
     pub enum_item: fn(number: Option<usize>, body: Content) -> Content,
     /// An item in a term list: `/ Term: Details`.
@@ -7,7 +7,7 @@ use crate::diag::{At, Hint, SourceResult};
 use crate::eval::{bail, Datetime};
 use crate::geom::{Align, Axes, Color, Dir, Em, GenAlign};
 use crate::model::{Location, Selector};
-use crate::syntax::Span;
+use crate::syntax::{Span, Spanned};
 
 /// Call a method on a value.
 pub fn call(
@@ -25,6 +25,14 @@ pub fn call(
             "lighten" => color.lighten(args.expect("amount")?).into_value(),
             "darken" => color.darken(args.expect("amount")?).into_value(),
             "negate" => color.negate().into_value(),
+            "saturate" => {
+                let Spanned { v: amount, span } = args.expect("amount")?;
+                color.saturate(amount).at(span)?.into_value()
+            }
+            "desaturate" => {
+                let Spanned { v: amount, span } = args.expect("amount")?;
+                color.desaturate(amount).at(span)?.into_value()
+            }
             "kind" => match color {
                 Color::Luma(_) => vm.items.luma_func.into_value(),
                 Color::Rgba(_) => vm.items.rgb_func.into_value(),
@@ -380,6 +388,8 @@ pub fn methods_on(type_name: &str) -> &[(&'static str, bool)] {
             ("lighten", true),
             ("darken", true),
             ("negate", false),
+            ("saturate", true),
+            ("desaturate", true),
             ("kind", false),
             ("hex", false),
             ("rgba", false),
@@ -668,7 +668,7 @@ impl Eval for ast::ListItem {
 
     #[tracing::instrument(name = "ListItem::eval", skip_all)]
     fn eval(&self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        Ok((vm.items.list_item)(self.body().eval(vm)?))
+        Ok((vm.items.list_item)(self.checked(), self.body().eval(vm)?))
     }
 }
 
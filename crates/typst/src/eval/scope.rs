@@ -126,6 +126,11 @@ impl Scope {
         self.0.get_mut(var).map(Slot::write)
     }
 
+    /// Remove a binding, returning its value if it existed.
+    pub fn remove(&mut self, var: &str) -> Option<Value> {
+        self.0.remove(var).map(|slot| slot.value)
+    }
+
     /// Iterate over all definitions.
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Value)> {
         self.0.iter().map(|(k, v)| (k, v.read()))
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use ecow::{eco_vec, EcoVec};
 
 use super::Value;
 use crate::diag::SourceDiagnostic;
+use crate::geom::Size;
 use crate::syntax::{FileId, Span};
 use crate::util::hash128;
 
@@ -14,6 +16,7 @@ pub struct Tracer {
     values: EcoVec<Value>,
     warnings: EcoVec<SourceDiagnostic>,
     warnings_set: HashSet<u128>,
+    progress: Option<Arc<dyn Fn(usize, Size) + Send + Sync>>,
 }
 
 impl Tracer {
@@ -27,9 +30,23 @@ impl Tracer {
             values: eco_vec![],
             warnings: eco_vec![],
             warnings_set: HashSet::new(),
+            progress: None,
         }
     }
 
+    /// Register a callback that is invoked whenever a page is laid out with
+    /// the page's zero-based index and size.
+    ///
+    /// Since the document may be laid out multiple times until all
+    /// introspections stabilize, a page can be reported more than once.
+    pub fn with_progress(
+        mut self,
+        f: impl Fn(usize, Size) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(f));
+        self
+    }
+
     /// Get the traced values.
     pub fn values(self) -> EcoVec<Value> {
         self.values
@@ -59,6 +76,13 @@ impl Tracer {
         }
     }
 
+    /// Report that a page was laid out.
+    pub fn page(&mut self, index: usize, size: Size) {
+        if let Some(progress) = &self.progress {
+            progress(index, size);
+        }
+    }
+
     /// Add a warning.
     pub fn warn(&mut self, warning: SourceDiagnostic) {
         // Check if warning is a duplicate.
@@ -4,6 +4,8 @@ mod pdf;
 mod render;
 mod svg;
 
-pub use self::pdf::pdf;
-pub use self::render::{render, render_merged};
+pub use self::pdf::{pdf, pdf_range};
+pub use self::render::{
+    render, render_contact_sheet, render_merged, render_with, ContactSheet, RenderOptions,
+};
 pub use self::svg::{svg, svg_merged};
@@ -7,8 +7,10 @@ use ttf_parser::{name_id, GlyphId, Tag};
 use unicode_general_category::GeneralCategory;
 
 use super::{deflate, EmExt, PdfContext, RefExt};
-use crate::eval::Bytes;
+use crate::diag::warning;
+use crate::eval::{Bytes, Tracer};
 use crate::font::Font;
+use crate::syntax::Span;
 use crate::util::SliceExt;
 
 const CMAP_NAME: Name = Name(b"Custom");
@@ -20,7 +22,7 @@ const SYSTEM_INFO: SystemInfo = SystemInfo {
 
 /// Embed all used fonts into the PDF.
 #[tracing::instrument(skip_all)]
-pub fn write_fonts(ctx: &mut PdfContext) {
+pub fn write_fonts(ctx: &mut PdfContext, tracer: &mut Tracer) {
     for font in ctx.font_map.items() {
         let type0_ref = ctx.alloc.bump();
         let cid_ref = ctx.alloc.bump();
@@ -141,7 +143,17 @@ pub fn write_fonts(ctx: &mut PdfContext) {
 
         // Subset and write the font's bytes.
         let glyphs: Vec<_> = glyph_set.keys().copied().collect();
-        let data = subset_font(font, &glyphs);
+        let data = subset_font(font, &glyphs).unwrap_or_else(|err| {
+            // The font is still embedded, just in full, so this only affects
+            // the file size.
+            let span = ctx.font_spans.get(font).copied().unwrap_or_else(Span::detached);
+            tracer.warn(warning!(
+                span,
+                "failed to subset font {} ({err}), embedding it in full",
+                font.info().family,
+            ));
+            deflate(font.data()).into()
+        });
         let mut stream = ctx.writer.stream(data_ref, &data);
         stream.filter(Filter::FlateDecode);
 
@@ -153,14 +165,13 @@ pub fn write_fonts(ctx: &mut PdfContext) {
     }
 }
 
-/// Subset a font to the given glyphs.
+/// Subset a font to the given glyphs and compress it.
 #[comemo::memoize]
-fn subset_font(font: &Font, glyphs: &[u16]) -> Bytes {
-    let data = font.data();
+fn subset_font(font: &Font, glyphs: &[u16]) -> Result<Bytes, EcoString> {
     let profile = subsetter::Profile::pdf(glyphs);
-    let subsetted = subsetter::subset(data, font.index(), profile);
-    let data = subsetted.as_deref().unwrap_or(data);
-    deflate(data).into()
+    let subsetted = subsetter::subset(font.data(), font.index(), profile)
+        .map_err(|err| eco_format!("{err}"))?;
+    Ok(deflate(&subsetted).into())
 }
 
 /// Create a /ToUnicode CMap.
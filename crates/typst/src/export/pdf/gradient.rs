@@ -0,0 +1,119 @@
+use pdf_writer::types::FunctionShadingType;
+use pdf_writer::{Finish, PdfWriter, Ref};
+
+use super::{AbsExt, PdfContext, RefExt};
+use crate::geom::{Color, Gradient, Point, Transform};
+
+/// A gradient positioned on a page.
+///
+/// PDF patterns live in the default coordinate system of the page or group
+/// they are used in. Thus, every item painted with a gradient needs its own
+/// pattern whose matrix maps the item's coordinate system onto that one.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PdfGradient {
+    /// The gradient itself.
+    pub gradient: Gradient,
+    /// The start of the gradient's axis in the item's coordinate system.
+    pub start: Point,
+    /// The end of the gradient's axis in the item's coordinate system.
+    pub end: Point,
+    /// The transform from the item's coordinate system to the page's or
+    /// group's.
+    pub transform: Transform,
+}
+
+/// Embed all used gradients into the PDF as shading patterns.
+#[tracing::instrument(skip_all)]
+pub fn write_gradients(ctx: &mut PdfContext) {
+    for pdf_gradient in ctx.gradient_map.items() {
+        let function = write_function(&mut ctx.writer, &mut ctx.alloc, pdf_gradient);
+        let pattern_ref = ctx.alloc.bump();
+        ctx.gradient_refs.push(pattern_ref);
+
+        let PdfGradient { start, end, transform, .. } = pdf_gradient;
+        let mut pattern = ctx.writer.shading_pattern(pattern_ref);
+        let mut shading = pattern.function_shading();
+        shading.shading_type(FunctionShadingType::Axial);
+        shading.color_space().srgb();
+        shading.function(function);
+        shading.coords([
+            start.x.to_f32(),
+            start.y.to_f32(),
+            end.x.to_f32(),
+            end.y.to_f32(),
+        ]);
+        shading.extend([true, true]);
+        shading.finish();
+
+        let Transform { sx, ky, kx, sy, tx, ty } = *transform;
+        pattern.matrix([
+            sx.get() as f32,
+            ky.get() as f32,
+            kx.get() as f32,
+            sy.get() as f32,
+            tx.to_f32(),
+            ty.to_f32(),
+        ]);
+    }
+}
+
+/// Write a function that maps positions along the gradient's axis to colors.
+///
+/// Each pair of adjacent stops is interpolated linearly and the resulting
+/// functions are stitched together at the stop offsets. Before the first and
+/// after the last stop, the color is constant. PDF shadings are opaque, so the
+/// alpha component of the stops is ignored.
+fn write_function(
+    writer: &mut PdfWriter,
+    alloc: &mut Ref,
+    pdf_gradient: &PdfGradient,
+) -> Ref {
+    let components = |color: Color| {
+        let c = color.to_rgba();
+        [c.r as f32 / 255.0, c.g as f32 / 255.0, c.b as f32 / 255.0]
+    };
+
+    // Collect the segments of the domain as pairs of colors and ranges,
+    // padded with constant segments up to the ends of the domain.
+    let stops = pdf_gradient.gradient.stops();
+    let (first, start) = stops[0];
+    let (last, end) = stops[stops.len() - 1];
+    let mut segments = vec![(first, first, 0.0, start.get())];
+    for window in stops.windows(2) {
+        let ((c0, o0), (c1, o1)) = (window[0], window[1]);
+        segments.push((c0, c1, o0.get(), o1.get()));
+    }
+    segments.push((last, last, end.get(), 1.0));
+    segments.retain(|&(_, _, from, to)| from < to);
+    if segments.is_empty() {
+        segments.push((last, last, 0.0, 1.0));
+    }
+
+    let mut functions = vec![];
+    let mut encode = vec![];
+    for &(c0, c1, _, _) in &segments {
+        let id = alloc.bump();
+        writer
+            .exponential_function(id)
+            .domain([0.0, 1.0])
+            .c0(components(c0))
+            .c1(components(c1))
+            .n(1.0);
+
+        functions.push(id);
+        encode.extend([0.0, 1.0]);
+    }
+
+    // The segments are separated at the start of all but the first one.
+    let bounds = segments.iter().skip(1).map(|&(_, _, from, _)| from as f32);
+
+    let id = alloc.bump();
+    writer
+        .stitching_function(id)
+        .domain([0.0, 1.0])
+        .functions(functions)
+        .bounds(bounds)
+        .encode(encode);
+
+    id
+}
@@ -0,0 +1,40 @@
+use pdf_writer::{Filter, Finish, Name, Rect};
+
+use super::{deflate, AbsExt, PdfContext, RefExt};
+use crate::geom::Size;
+
+/// A group whose contents are composited as a whole.
+///
+/// The group is drawn into its own coordinate system, so it can be reused
+/// wherever the same contents appear.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PdfGroup {
+    /// The size of the group's frame.
+    pub size: Size,
+    /// The uncompressed content stream of the group.
+    pub content: Vec<u8>,
+}
+
+/// Embed all opacity groups into the PDF as transparency group XObjects.
+#[tracing::instrument(skip_all)]
+pub fn write_groups(ctx: &mut PdfContext) {
+    for PdfGroup { size, content } in ctx.group_map.items() {
+        let group_ref = ctx.alloc.bump();
+        ctx.group_refs.push(group_ref);
+
+        let data = deflate(content);
+        let mut form = ctx.writer.form_xobject(group_ref, &data);
+        form.bbox(Rect::new(0.0, 0.0, size.x.to_f32(), size.y.to_f32()));
+        form.group()
+            .transparency()
+            .isolated(true)
+            .knockout(false)
+            .color_space()
+            .srgb();
+
+        // Groups can use the same resources as pages.
+        form.pair(Name(b"Resources"), ctx.global_resources_ref);
+        form.filter(Filter::FlateDecode);
+        form.finish();
+    }
+}
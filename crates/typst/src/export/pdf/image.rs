@@ -1,11 +1,12 @@
+use std::collections::HashMap;
 use std::io::Cursor;
 
 use image::{DynamicImage, GenericImageView, Rgba};
-use pdf_writer::{Filter, Finish};
+use pdf_writer::{Dict, Filter, Finish, Name, Null, Obj, PdfWriter, Rect, Ref, Str};
 
 use super::{deflate, PdfContext, RefExt};
 use crate::eval::Bytes;
-use crate::image::{DecodedImage, Image, RasterFormat};
+use crate::image::{DecodedImage, Image, PdfDict, PdfObject, PdfPage, RasterFormat};
 
 /// Embed all used images into the PDF.
 #[tracing::instrument(skip_all)]
@@ -79,7 +80,86 @@ pub fn write_images(ctx: &mut PdfContext) {
                 );
                 ctx.alloc = next_ref;
             }
+            DecodedImage::Pdf(page) => {
+                write_pdf_page(&mut ctx.writer, &mut ctx.alloc, image_ref, page);
+            }
+        }
+    }
+}
+
+/// Embed a page from another PDF file as a form XObject.
+///
+/// The page's resources are copied over with fresh object numbers. Like other
+/// images, the form is scaled to the unit square.
+fn write_pdf_page(
+    writer: &mut PdfWriter,
+    alloc: &mut Ref,
+    form_ref: Ref,
+    page: &PdfPage,
+) {
+    let refs: HashMap<u32, Ref> =
+        page.objects.keys().map(|&id| (id, alloc.bump())).collect();
+
+    for (id, object) in &page.objects {
+        let object_ref = refs[id];
+        match object {
+            PdfObject::Stream(dict, data) => {
+                let mut stream = writer.stream(object_ref, data);
+                write_dict_entries(&mut stream, dict, &refs);
+            }
+            object => write_object(writer.indirect(object_ref), object, &refs),
+        }
+    }
+
+    let [x0, y0, x1, y1] = page.media_box;
+    let (w, h) = (x1 - x0, y1 - y0);
+    let data = deflate(&page.content);
+    let mut form = writer.form_xobject(form_ref, &data);
+    form.bbox(Rect::new(x0, y0, x1, y1));
+    form.matrix([1.0 / w, 0.0, 0.0, 1.0 / h, -x0 / w, -y0 / h]);
+    form.filter(Filter::FlateDecode);
+    write_object(form.insert(Name(b"Resources")), &page.resources, &refs);
+    form.finish();
+}
+
+/// Write the entries of a copied dictionary, leaving out stream lengths as
+/// they are written anew.
+fn write_dict_entries(dict: &mut Dict, entries: &PdfDict, refs: &HashMap<u32, Ref>) {
+    for (key, value) in entries {
+        if key != b"Length" {
+            write_object(dict.insert(Name(key)), value, refs);
+        }
+    }
+}
+
+/// Write a copied object, renumbering its references.
+fn write_object(obj: Obj, object: &PdfObject, refs: &HashMap<u32, Ref>) {
+    match object {
+        PdfObject::Null => obj.primitive(Null),
+        PdfObject::Bool(v) => obj.primitive(*v),
+        PdfObject::Int(v) => match i32::try_from(*v) {
+            Ok(v) => obj.primitive(v),
+            // Only 32-bit integers can be written, so larger ones are written
+            // as real numbers.
+            Err(_) => obj.primitive(*v as f32),
+        },
+        PdfObject::Real(v) => obj.primitive(*v),
+        PdfObject::Str(v) => obj.primitive(Str(v)),
+        PdfObject::Name(v) => obj.primitive(Name(v)),
+        PdfObject::Array(items) => {
+            let mut array = obj.array();
+            for item in items {
+                write_object(array.push(), item, refs);
+            }
+        }
+        // Streams can't be nested, so their data is dropped here.
+        PdfObject::Dict(dict) | PdfObject::Stream(dict, _) => {
+            write_dict_entries(&mut obj.dict(), dict, refs);
         }
+        PdfObject::Ref(id) => match refs.get(id) {
+            Some(&object_ref) => obj.primitive(object_ref),
+            None => obj.primitive(Null),
+        },
     }
 }
 
@@ -2,13 +2,18 @@
 
 mod external_graphics_state;
 mod font;
+mod gradient;
+mod group;
 mod image;
 mod outline;
 mod page;
+mod pattern;
 
 use std::cmp::Eq;
 use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use ecow::EcoString;
 use pdf_writer::types::Direction;
@@ -17,23 +22,52 @@ use xmp_writer::{LangId, RenditionClass, XmpWriter};
 
 use self::page::Page;
 use crate::doc::{Document, Lang};
+use crate::eval::{Datetime, Tracer};
 use crate::font::Font;
 use crate::geom::{Abs, Dir, Em};
 use crate::image::Image;
 use crate::model::Introspector;
+use crate::syntax::Span;
 
 use external_graphics_state::ExternalGraphicsState;
+use gradient::PdfGradient;
+use group::PdfGroup;
+use pattern::PdfPattern;
 
 /// Export a document into a PDF file.
 ///
+/// Problems that don't prevent the export, like a font that can't be
+/// subsetted, are reported as warnings to the tracer.
+///
+/// Returns the raw bytes making up the PDF file.
+#[tracing::instrument(skip_all)]
+pub fn pdf(document: &Document, tracer: &mut Tracer) -> Vec<u8> {
+    pdf_range(document, 0..document.pages.len(), tracer)
+}
+
+/// Export some pages of a document into a PDF file.
+///
+/// The range holds zero-based page indices and is clamped to the document's
+/// pages. Links and outline entries pointing to pages outside of the range are
+/// left without a destination.
+///
 /// Returns the raw bytes making up the PDF file.
 #[tracing::instrument(skip_all)]
-pub fn pdf(document: &Document) -> Vec<u8> {
-    let mut ctx = PdfContext::new(document);
-    page::construct_pages(&mut ctx, &document.pages);
-    font::write_fonts(&mut ctx);
+pub fn pdf_range(
+    document: &Document,
+    range: Range<usize>,
+    tracer: &mut Tracer,
+) -> Vec<u8> {
+    let end = range.end.min(document.pages.len());
+    let start = range.start.min(end);
+    let mut ctx = PdfContext::new(document, start);
+    page::construct_pages(&mut ctx, &document.pages[start..end]);
+    font::write_fonts(&mut ctx, tracer);
     image::write_images(&mut ctx);
     external_graphics_state::write_external_graphics_states(&mut ctx);
+    gradient::write_gradients(&mut ctx);
+    pattern::write_patterns(&mut ctx);
+    group::write_groups(&mut ctx);
     page::write_page_tree(&mut ctx);
     write_catalog(&mut ctx);
     ctx.writer.finish()
@@ -47,18 +81,27 @@ const D65_GRAY: Name<'static> = Name(b"d65gray");
 pub struct PdfContext<'a> {
     document: &'a Document,
     introspector: Introspector,
+    /// The index of the first exported page in the document.
+    first_page: usize,
     writer: PdfWriter,
     pages: Vec<Page>,
     page_heights: Vec<f32>,
     alloc: Ref,
     page_tree_ref: Ref,
+    global_resources_ref: Ref,
     font_refs: Vec<Ref>,
     image_refs: Vec<Ref>,
     ext_gs_refs: Vec<Ref>,
+    gradient_refs: Vec<Ref>,
+    pattern_refs: Vec<Ref>,
+    group_refs: Vec<Ref>,
     page_refs: Vec<Ref>,
     font_map: Remapper<Font>,
     image_map: Remapper<Image>,
     ext_gs_map: Remapper<ExternalGraphicsState>,
+    gradient_map: Remapper<PdfGradient>,
+    pattern_map: Remapper<PdfPattern>,
+    group_map: Remapper<PdfGroup>,
     /// For each font a mapping from used glyphs to their text representation.
     /// May contain multiple chars in case of ligatures or similar things. The
     /// same glyph can have a different text representation within one document,
@@ -66,34 +109,55 @@ pub struct PdfContext<'a> {
     /// PDF's /ToUnicode map for glyphs that don't have an entry in the font's
     /// cmap. This is important for copy-paste and searching.
     glyph_sets: HashMap<Font, BTreeMap<u16, EcoString>>,
+    /// For each font the span of the first text that uses it, for warnings.
+    font_spans: HashMap<Font, Span>,
     languages: HashMap<Lang, usize>,
 }
 
 impl<'a> PdfContext<'a> {
-    fn new(document: &'a Document) -> Self {
+    fn new(document: &'a Document, first_page: usize) -> Self {
         let mut alloc = Ref::new(1);
         let page_tree_ref = alloc.bump();
+        let global_resources_ref = alloc.bump();
         Self {
             document,
             introspector: Introspector::new(&document.pages),
+            first_page,
             writer: PdfWriter::new(),
             pages: vec![],
             page_heights: vec![],
             alloc,
             page_tree_ref,
+            global_resources_ref,
             page_refs: vec![],
             font_refs: vec![],
             image_refs: vec![],
             ext_gs_refs: vec![],
+            gradient_refs: vec![],
+            pattern_refs: vec![],
+            group_refs: vec![],
             font_map: Remapper::new(),
             image_map: Remapper::new(),
             ext_gs_map: Remapper::new(),
+            gradient_map: Remapper::new(),
+            pattern_map: Remapper::new(),
+            group_map: Remapper::new(),
             glyph_sets: HashMap::new(),
+            font_spans: HashMap::new(),
             languages: HashMap::new(),
         }
     }
 }
 
+impl PdfContext<'_> {
+    /// The index of a page of the document among the exported pages, if it
+    /// is exported.
+    fn page_index(&self, page: NonZeroUsize) -> Option<usize> {
+        let index = (page.get() - 1).checked_sub(self.first_page)?;
+        (index < self.page_refs.len()).then_some(index)
+    }
+}
+
 /// Write the document catalog.
 #[tracing::instrument(skip_all)]
 fn write_catalog(ctx: &mut PdfContext) {
@@ -125,6 +189,27 @@ fn write_catalog(ctx: &mut PdfContext) {
         info.author(TextStr(&authors.join(", ")));
         xmp.creator(authors.iter().map(|s| s.as_str()));
     }
+
+    if let Some(subject) = &ctx.document.subject {
+        info.subject(TextStr(subject));
+        xmp.description([(None, subject.as_str())]);
+    }
+
+    let keywords = &ctx.document.keywords;
+    if !keywords.is_empty() {
+        info.keywords(TextStr(&keywords.join(", ")));
+        xmp.subject(keywords.iter().map(|s| s.as_str()));
+    }
+
+    if let Some(date) = ctx.document.date {
+        if let Some(pdf_date) = pdf_date(date) {
+            info.creation_date(pdf_date);
+        }
+        if let Some(xmp_date) = xmp_date(date) {
+            xmp.create_date(xmp_date);
+        }
+    }
+
     info.creator(TextStr("Typst"));
     info.finish();
     xmp.creator_tool("Typst");
@@ -156,6 +241,37 @@ fn write_catalog(ctx: &mut PdfContext) {
     }
 }
 
+/// Convert a datetime into a PDF date, if it has a date component.
+fn pdf_date(datetime: Datetime) -> Option<pdf_writer::Date> {
+    let year = datetime.year()?.try_into().ok()?;
+    let mut date = pdf_writer::Date::new(year);
+    if let Some(month) = datetime.month() {
+        date = date.month(month);
+    }
+    if let Some(day) = datetime.day() {
+        date = date.day(day);
+    }
+    if let (Some(hour), Some(minute), Some(second)) =
+        (datetime.hour(), datetime.minute(), datetime.second())
+    {
+        date = date.hour(hour).minute(minute).second(second);
+    }
+    Some(date)
+}
+
+/// Convert a datetime into an XMP date, if it has a date component.
+fn xmp_date(datetime: Datetime) -> Option<xmp_writer::DateTime> {
+    Some(xmp_writer::DateTime {
+        year: datetime.year()?.try_into().ok()?,
+        month: datetime.month(),
+        day: datetime.day(),
+        hour: datetime.hour(),
+        minute: datetime.minute(),
+        second: datetime.second(),
+        timezone: None,
+    })
+}
+
 /// Compress data with the DEFLATE algorithm.
 #[tracing::instrument(skip_all)]
 fn deflate(data: &[u8]) -> Vec<u8> {
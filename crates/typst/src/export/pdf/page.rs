@@ -1,17 +1,20 @@
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use pdf_writer::types::{
     ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
 };
-use pdf_writer::writers::ColorSpace;
+use pdf_writer::writers::{ColorSpace, Resources};
 use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str};
 
 use super::external_graphics_state::ExternalGraphicsState;
+use super::gradient::PdfGradient;
+use super::group::PdfGroup;
+use super::pattern::PdfPattern;
 use super::{deflate, AbsExt, EmExt, PdfContext, RefExt, D65_GRAY, SRGB};
 use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Color, Em, Geometry, LineCap, LineJoin, Numeric, Paint, Point, Ratio,
-    Shape, Size, Stroke, Transform,
+    self, Abs, Color, Em, Geometry, Gradient, LineCap, LineJoin, Numeric, Paint, Pattern,
+    Point, Ratio, Shape, Size, Stroke, Transform,
 };
 use crate::image::Image;
 
@@ -30,17 +33,7 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     ctx.page_refs.push(page_ref);
     ctx.page_heights.push(frame.height().to_f32());
 
-    let mut ctx = PageContext {
-        parent: ctx,
-        page_ref,
-        uses_opacities: false,
-        content: Content::new(),
-        state: State::default(),
-        saves: vec![],
-        bottom: 0.0,
-        links: vec![],
-    };
-
+    let mut ctx = PageContext::new(ctx);
     let size = frame.size();
 
     // Make the coordinate system start at the top-left.
@@ -60,7 +53,7 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     let page = Page {
         size,
         content: ctx.content,
-        id: ctx.page_ref,
+        id: page_ref,
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
     };
@@ -68,6 +61,18 @@ pub fn construct_page(ctx: &mut PdfContext, frame: &Frame) {
     ctx.parent.pages.push(page);
 }
 
+/// Construct the content stream of a pattern tile.
+///
+/// The tile is drawn in its own coordinate system, with the origin in the
+/// top-left corner and the y-axis pointing down. Returns the uncompressed
+/// content and whether it uses opacities.
+#[tracing::instrument(skip_all)]
+pub fn construct_tile(ctx: &mut PdfContext, frame: &Frame) -> (Vec<u8>, bool) {
+    let mut ctx = PageContext::new(ctx);
+    write_frame(&mut ctx, frame);
+    (ctx.content.finish(), ctx.uses_opacities)
+}
+
 /// Write the page tree.
 #[tracing::instrument(skip_all)]
 pub fn write_page_tree(ctx: &mut PdfContext) {
@@ -80,7 +85,12 @@ pub fn write_page_tree(ctx: &mut PdfContext) {
         .count(ctx.page_refs.len() as i32)
         .kids(ctx.page_refs.iter().copied());
 
-    let mut resources = pages.resources();
+    pages.pair(Name(b"Resources"), ctx.global_resources_ref);
+    pages.finish();
+
+    // The resources are shared between all pages and pattern tiles.
+    let mut resources =
+        ctx.writer.indirect(ctx.global_resources_ref).start::<Resources>();
     let mut spaces = resources.color_spaces();
     spaces.insert(SRGB).start::<ColorSpace>().srgb();
     spaces.insert(D65_GRAY).start::<ColorSpace>().d65_gray();
@@ -94,13 +104,17 @@ pub fn write_page_tree(ctx: &mut PdfContext) {
 
     fonts.finish();
 
-    let mut images = resources.x_objects();
+    let mut x_objects = resources.x_objects();
     for (image_ref, im) in ctx.image_map.pdf_indices(&ctx.image_refs) {
         let name = eco_format!("Im{}", im);
-        images.pair(Name(name.as_bytes()), image_ref);
+        x_objects.pair(Name(name.as_bytes()), image_ref);
+    }
+    for (group_ref, g) in ctx.group_map.pdf_indices(&ctx.group_refs) {
+        let name = eco_format!("Xg{}", g);
+        x_objects.pair(Name(name.as_bytes()), group_ref);
     }
 
-    images.finish();
+    x_objects.finish();
 
     let mut ext_gs_states = resources.ext_g_states();
     for (gs_ref, gs) in ctx.ext_gs_map.pdf_indices(&ctx.ext_gs_refs) {
@@ -109,8 +123,18 @@ pub fn write_page_tree(ctx: &mut PdfContext) {
     }
     ext_gs_states.finish();
 
+    let mut patterns = resources.patterns();
+    for (gradient_ref, gr) in ctx.gradient_map.pdf_indices(&ctx.gradient_refs) {
+        let name = eco_format!("Gr{}", gr);
+        patterns.pair(Name(name.as_bytes()), gradient_ref);
+    }
+    for (pattern_ref, p) in ctx.pattern_map.pdf_indices(&ctx.pattern_refs) {
+        let name = eco_format!("P{}", p);
+        patterns.pair(Name(name.as_bytes()), pattern_ref);
+    }
+    patterns.finish();
+
     resources.finish();
-    pages.finish();
 }
 
 /// Write a page tree node.
@@ -154,9 +178,9 @@ fn write_page(ctx: &mut PdfContext, page: Page) {
             Destination::Location(loc) => ctx.introspector.position(loc),
         };
 
-        let index = pos.page.get() - 1;
         let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
-        if let Some(&height) = ctx.page_heights.get(index) {
+        if let Some(index) = ctx.page_index(pos.page) {
+            let height = ctx.page_heights[index];
             annotation
                 .action()
                 .action_type(ActionType::GoTo)
@@ -191,13 +215,15 @@ pub struct Page {
 /// An exporter for the contents of a single PDF page.
 struct PageContext<'a, 'b> {
     parent: &'a mut PdfContext<'b>,
-    page_ref: Ref,
     content: Content,
     state: State,
     saves: Vec<State>,
     bottom: f32,
     uses_opacities: bool,
     links: Vec<(Destination, Rect)>,
+    /// The transform from the content stream's coordinate system to the
+    /// page's, which is not the identity within opacity groups.
+    link_transform: Transform,
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -213,7 +239,20 @@ struct State {
     stroke_space: Option<Name<'static>>,
 }
 
-impl PageContext<'_, '_> {
+impl<'a, 'b> PageContext<'a, 'b> {
+    fn new(parent: &'a mut PdfContext<'b>) -> Self {
+        Self {
+            parent,
+            uses_opacities: false,
+            content: Content::new(),
+            state: State::default(),
+            saves: vec![],
+            bottom: 0.0,
+            links: vec![],
+            link_transform: Transform::identity(),
+        }
+    }
+
     fn save_state(&mut self) {
         self.saves.push(self.state.clone());
         self.content.save_state();
@@ -238,26 +277,13 @@ impl PageContext<'_, '_> {
     }
 
     fn set_opacities(&mut self, stroke: Option<&Stroke>, fill: Option<&Paint>) {
-        let stroke_opacity = stroke
-            .map(|stroke| {
-                let Paint::Solid(color) = stroke.paint;
-                if let Color::Rgba(rgba_color) = color {
-                    rgba_color.a
-                } else {
-                    255
-                }
-            })
-            .unwrap_or(255);
-        let fill_opacity = fill
-            .map(|paint| {
-                let Paint::Solid(color) = paint;
-                if let Color::Rgba(rgba_color) = color {
-                    rgba_color.a
-                } else {
-                    255
-                }
-            })
-            .unwrap_or(255);
+        // Gradients and patterns are always drawn opaque.
+        let opacity = |paint: &Paint| match paint {
+            Paint::Solid(Color::Rgba(rgba_color)) => rgba_color.a,
+            _ => 255,
+        };
+        let stroke_opacity = stroke.map(|stroke| opacity(&stroke.paint)).unwrap_or(255);
+        let fill_opacity = fill.map(opacity).unwrap_or(255);
         self.set_external_graphics_state(&ExternalGraphicsState {
             stroke_opacity,
             fill_opacity,
@@ -286,23 +312,38 @@ impl PageContext<'_, '_> {
         }
     }
 
-    fn set_fill(&mut self, fill: &Paint) {
-        if self.state.fill.as_ref() != Some(fill) {
+    /// Set the fill for an item whose bounding box has the given origin and
+    /// size in the current coordinate system.
+    fn set_fill(&mut self, fill: &Paint, origin: Point, size: Size) {
+        // Gradients and patterns depend on the item's position, so they are
+        // always set anew.
+        if self.state.fill.as_ref() != Some(fill) || !matches!(fill, Paint::Solid(_)) {
             let f = |c| c as f32 / 255.0;
-            let Paint::Solid(color) = fill;
-            match color {
-                Color::Luma(c) => {
+            match fill {
+                Paint::Solid(Color::Luma(c)) => {
                     self.set_fill_color_space(D65_GRAY);
                     self.content.set_fill_gray(f(c.0));
                 }
-                Color::Rgba(c) => {
+                Paint::Solid(Color::Rgba(c)) => {
                     self.set_fill_color_space(SRGB);
                     self.content.set_fill_color([f(c.r), f(c.g), f(c.b)]);
                 }
-                Color::Cmyk(c) => {
+                Paint::Solid(Color::Cmyk(c)) => {
                     self.reset_fill_color_space();
                     self.content.set_fill_cmyk(f(c.c), f(c.m), f(c.y), f(c.k));
                 }
+                Paint::Gradient(gradient) => {
+                    let name = self.gradient_name(gradient, origin, size);
+                    self.reset_fill_color_space();
+                    self.content.set_fill_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_fill_pattern(None, Name(name.as_bytes()));
+                }
+                Paint::Pattern(pattern) => {
+                    let name = self.pattern_name(pattern, origin);
+                    self.reset_fill_color_space();
+                    self.content.set_fill_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_fill_pattern(None, Name(name.as_bytes()));
+                }
             }
             self.state.fill = Some(fill.clone());
         }
@@ -319,8 +360,12 @@ impl PageContext<'_, '_> {
         self.state.fill_space = None;
     }
 
-    fn set_stroke(&mut self, stroke: &Stroke) {
-        if self.state.stroke.as_ref() != Some(stroke) {
+    /// Set the stroke for an item whose bounding box has the given origin and
+    /// size in the current coordinate system.
+    fn set_stroke(&mut self, stroke: &Stroke, origin: Point, size: Size) {
+        if self.state.stroke.as_ref() != Some(stroke)
+            || !matches!(stroke.paint, Paint::Solid(_))
+        {
             let Stroke {
                 paint,
                 thickness,
@@ -331,20 +376,31 @@ impl PageContext<'_, '_> {
             } = stroke;
 
             let f = |c| c as f32 / 255.0;
-            let Paint::Solid(color) = paint;
-            match color {
-                Color::Luma(c) => {
+            match paint {
+                Paint::Solid(Color::Luma(c)) => {
                     self.set_stroke_color_space(D65_GRAY);
                     self.content.set_stroke_gray(f(c.0));
                 }
-                Color::Rgba(c) => {
+                Paint::Solid(Color::Rgba(c)) => {
                     self.set_stroke_color_space(SRGB);
                     self.content.set_stroke_color([f(c.r), f(c.g), f(c.b)]);
                 }
-                Color::Cmyk(c) => {
+                Paint::Solid(Color::Cmyk(c)) => {
                     self.reset_stroke_color_space();
                     self.content.set_stroke_cmyk(f(c.c), f(c.m), f(c.y), f(c.k));
                 }
+                Paint::Gradient(gradient) => {
+                    let name = self.gradient_name(gradient, origin, size);
+                    self.reset_stroke_color_space();
+                    self.content.set_stroke_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_stroke_pattern(None, Name(name.as_bytes()));
+                }
+                Paint::Pattern(pattern) => {
+                    let name = self.pattern_name(pattern, origin);
+                    self.reset_stroke_color_space();
+                    self.content.set_stroke_color_space(ColorSpaceOperand::Pattern);
+                    self.content.set_stroke_pattern(None, Name(name.as_bytes()));
+                }
             }
 
             self.content.set_line_width(thickness.to_f32());
@@ -381,6 +437,45 @@ impl PageContext<'_, '_> {
     fn reset_stroke_color_space(&mut self) {
         self.state.stroke_space = None;
     }
+
+    /// Register a gradient spanning the given bounding box and return the
+    /// name of its pattern.
+    fn gradient_name(
+        &mut self,
+        gradient: &Gradient,
+        origin: Point,
+        size: Size,
+    ) -> EcoString {
+        let (start, end) = gradient.axis(size);
+        let pdf_gradient = PdfGradient {
+            gradient: gradient.clone(),
+            start: origin + start,
+            end: origin + end,
+            transform: self.state.transform,
+        };
+
+        self.parent.gradient_map.insert(pdf_gradient.clone());
+        eco_format!("Gr{}", self.parent.gradient_map.map(pdf_gradient))
+    }
+
+    /// Register a pattern whose tiles start at the given origin and return
+    /// the name of its tiling pattern.
+    fn pattern_name(&mut self, pattern: &Pattern, origin: Point) -> EcoString {
+        let (content, uses_opacities) = construct_tile(self.parent, pattern.frame());
+        self.uses_opacities |= uses_opacities;
+
+        let pdf_pattern = PdfPattern {
+            pattern: pattern.clone(),
+            transform: self
+                .state
+                .transform
+                .pre_concat(Transform::translate(origin.x, origin.y)),
+            content,
+        };
+
+        self.parent.pattern_map.insert(pdf_pattern.clone());
+        eco_format!("P{}", self.parent.pattern_map.map(pdf_pattern))
+    }
 }
 
 /// Encode a frame into the content stream.
@@ -390,14 +485,15 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
         let y = pos.y.to_f32();
         match item {
             FrameItem::Group(group) => write_group(ctx, pos, group),
-            FrameItem::Text(text) => write_text(ctx, x, y, text),
-            FrameItem::Shape(shape, _) => write_shape(ctx, x, y, shape),
+            FrameItem::Text(text) => write_text(ctx, pos, text),
+            FrameItem::Shape(shape, _) => write_shape(ctx, pos, shape),
             FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
             FrameItem::Meta(meta, size) => match meta {
                 Meta::Link(dest) => write_link(ctx, pos, dest, *size),
                 Meta::Elem(_) => {}
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
+                Meta::Line => {}
             },
         }
     }
@@ -422,21 +518,63 @@ fn write_group(ctx: &mut PageContext, pos: Point, group: &GroupItem) {
         ctx.content.end_path();
     }
 
-    write_frame(ctx, &group.frame);
+    if group.opacity != Ratio::one() {
+        write_opacity_group(ctx, group);
+    } else {
+        write_frame(ctx, &group.frame);
+    }
+
     ctx.restore_state();
 }
 
+/// Encode a group with an opacity as a transparency group XObject.
+///
+/// The contents are drawn into the group first and then composited onto the
+/// page as a whole.
+fn write_opacity_group(ctx: &mut PageContext, group: &GroupItem) {
+    // The group is drawn in its own coordinate system, which is also the one
+    // its gradients and patterns are placed in. Links inside of it must still
+    // end up in page coordinates, though.
+    let link_transform = ctx.link_transform.pre_concat(ctx.state.transform);
+    let mut inner = PageContext::new(ctx.parent);
+    inner.link_transform = link_transform;
+    write_frame(&mut inner, &group.frame);
+    let content = inner.content.finish();
+    let links = inner.links;
+    ctx.links.extend(links);
+
+    let pdf_group = PdfGroup { size: group.frame.size(), content };
+
+    let alpha = (group.opacity.get().clamp(0.0, 1.0) * 255.0).round() as u8;
+    ctx.set_external_graphics_state(&ExternalGraphicsState {
+        stroke_opacity: alpha,
+        fill_opacity: alpha,
+    });
+    ctx.uses_opacities = true;
+
+    ctx.parent.group_map.insert(pdf_group.clone());
+    let name = eco_format!("Xg{}", ctx.parent.group_map.map(pdf_group));
+    ctx.content.x_object(Name(name.as_bytes()));
+}
+
 /// Encode a text run into the content stream.
-fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
+fn write_text(ctx: &mut PageContext, pos: Point, text: &TextItem) {
+    let x = pos.x.to_f32();
+    let y = pos.y.to_f32();
     *ctx.parent.languages.entry(text.lang).or_insert(0) += text.glyphs.len();
 
+    if let Some(glyph) = text.glyphs.iter().find(|g| !g.span.0.is_detached()) {
+        ctx.parent.font_spans.entry(text.font.clone()).or_insert(glyph.span.0);
+    }
+
     let glyph_set = ctx.parent.glyph_sets.entry(text.font.clone()).or_default();
     for g in &text.glyphs {
         let segment = &text.text[g.range()];
         glyph_set.entry(g.id).or_insert_with(|| segment.into());
     }
 
-    ctx.set_fill(&text.fill);
+    let (origin, size) = text.bbox();
+    ctx.set_fill(&text.fill, pos + origin, size);
     ctx.set_font(&text.font, text.size);
     ctx.set_opacities(None, Some(&text.fill));
     ctx.content.begin_text();
@@ -483,7 +621,9 @@ fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
 }
 
 /// Encode a geometrical shape into the content stream.
-fn write_shape(ctx: &mut PageContext, x: f32, y: f32, shape: &Shape) {
+fn write_shape(ctx: &mut PageContext, pos: Point, shape: &Shape) {
+    let x = pos.x.to_f32();
+    let y = pos.y.to_f32();
     let stroke = shape.stroke.as_ref().and_then(|stroke| {
         if stroke.thickness.to_f32() > 0.0 {
             Some(stroke)
@@ -496,12 +636,13 @@ fn write_shape(ctx: &mut PageContext, x: f32, y: f32, shape: &Shape) {
         return;
     }
 
+    let size = shape.geometry.bbox_size();
     if let Some(fill) = &shape.fill {
-        ctx.set_fill(fill);
+        ctx.set_fill(fill, pos, size);
     }
 
     if let Some(stroke) = stroke {
-        ctx.set_stroke(stroke);
+        ctx.set_stroke(stroke, pos, size);
     }
 
     ctx.set_opacities(stroke, shape.fill.as_ref());
@@ -590,13 +731,14 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let mut max_y = -Abs::inf();
 
     // Compute the bounding box of the transformed link.
+    let ts = ctx.link_transform.pre_concat(ctx.state.transform);
     for point in [
         pos,
         pos + Point::with_x(size.x),
         pos + Point::with_y(size.y),
         pos + size.to_point(),
     ] {
-        let t = point.transform(ctx.state.transform);
+        let t = point.transform(ts);
         min_x.set_min(t.x);
         min_y.set_min(t.y);
         max_x.set_max(t.x);
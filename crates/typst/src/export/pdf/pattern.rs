@@ -0,0 +1,57 @@
+use pdf_writer::types::{PaintType, TilingType};
+use pdf_writer::{Filter, Finish, Name, Rect};
+
+use super::{deflate, AbsExt, PdfContext, RefExt};
+use crate::geom::{Pattern, Transform};
+
+/// A pattern positioned on a page.
+///
+/// Just like for gradients, the pattern matrix depends on where the pattern
+/// is used, so each use site gets its own pattern object.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PdfPattern {
+    /// The pattern itself.
+    pub pattern: Pattern,
+    /// The transform from the pattern's coordinate system to the page's or
+    /// group's.
+    pub transform: Transform,
+    /// The uncompressed content stream of a single tile.
+    pub content: Vec<u8>,
+}
+
+/// Embed all used patterns into the PDF as tiling patterns.
+#[tracing::instrument(skip_all)]
+pub fn write_patterns(ctx: &mut PdfContext) {
+    for PdfPattern { pattern, transform, content } in ctx.pattern_map.items() {
+        let pattern_ref = ctx.alloc.bump();
+        ctx.pattern_refs.push(pattern_ref);
+
+        let period = pattern.period();
+        let (w, h) = (period.x.to_f32(), period.y.to_f32());
+        let data = deflate(content);
+
+        let mut tiling = ctx.writer.tiling_pattern(pattern_ref, &data);
+        tiling
+            .tiling_type(TilingType::ConstantSpacing)
+            .paint_type(PaintType::Colored)
+            .bbox(Rect::new(0.0, 0.0, w, h))
+            .x_step(w)
+            .y_step(h);
+
+        // Tiles can use the same resources as pages.
+        tiling.pair(Name(b"Resources"), ctx.global_resources_ref);
+
+        let Transform { sx, ky, kx, sy, tx, ty } = *transform;
+        tiling.matrix([
+            sx.get() as f32,
+            ky.get() as f32,
+            kx.get() as f32,
+            sy.get() as f32,
+            tx.to_f32(),
+            ty.to_f32(),
+        ]);
+
+        tiling.filter(Filter::FlateDecode);
+        tiling.finish();
+    }
+}
@@ -1,8 +1,11 @@
 //! Rendering into raster images.
 
+use std::hash::{Hash, Hasher};
 use std::io::Read;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use ecow::eco_format;
 use image::imageops::FilterType;
 use image::{GenericImageView, Rgba};
 use pixglyph::Bitmap;
@@ -11,19 +14,30 @@ use tiny_skia as sk;
 use ttf_parser::{GlyphId, OutlineBuilder};
 use usvg::{NodeExt, TreeParsing};
 
-use crate::doc::{Frame, FrameItem, GroupItem, Meta, TextItem};
+use crate::doc::{Frame, FrameItem, Glyph, GroupItem, Lang, Meta, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Color, Geometry, LineCap, LineJoin, Paint, PathItem, Shape, Size, Stroke,
-    Transform,
+    self, Abs, Color, Em, Geometry, Gradient, LineCap, LineJoin, Paint, PathItem,
+    Pattern, Point, Ratio, Shape, Size, Stroke, Transform,
 };
 use crate::image::{DecodedImage, Image};
+use crate::syntax::Span;
 
 /// Export a frame into a raster image.
 ///
 /// This renders the frame at the given number of pixels per point and returns
 /// the resulting `tiny-skia` pixel buffer.
 pub fn render(frame: &Frame, pixel_per_pt: f32, fill: Color) -> sk::Pixmap {
+    render_with(frame, pixel_per_pt, fill, &RenderOptions::default())
+}
+
+/// Export a frame into a raster image with custom rasterization options.
+pub fn render_with(
+    frame: &Frame,
+    pixel_per_pt: f32,
+    fill: Color,
+    opts: &RenderOptions,
+) -> sk::Pixmap {
     let size = frame.size();
     let pxw = (pixel_per_pt * size.x.to_f32()).round().max(1.0) as u32;
     let pxh = (pixel_per_pt * size.y.to_f32()).round().max(1.0) as u32;
@@ -32,7 +46,7 @@ pub fn render(frame: &Frame, pixel_per_pt: f32, fill: Color) -> sk::Pixmap {
     canvas.fill(fill.into());
 
     let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
-    render_frame(&mut canvas, ts, None, frame);
+    render_frame(&mut canvas, ts, None, opts, frame);
 
     canvas
 }
@@ -78,11 +92,194 @@ pub fn render_merged(
     canvas
 }
 
+/// Options for arranging pages into a contact sheet.
+#[derive(Debug, Clone)]
+pub struct ContactSheet {
+    /// The number of thumbnails per row.
+    pub columns: NonZeroUsize,
+    /// The width of each thumbnail in pixels.
+    pub thumbnail_width: f32,
+    /// The space around and between the thumbnails in pixels.
+    pub gap: f32,
+    /// The font in which the page numbers are written below the thumbnails.
+    /// If this is `None`, the thumbnails are not labelled.
+    pub label_font: Option<Font>,
+    /// The background of each thumbnail.
+    pub page_fill: Color,
+    /// The background of the sheet between the thumbnails.
+    pub fill: Color,
+}
+
+impl Default for ContactSheet {
+    fn default() -> Self {
+        Self {
+            columns: NonZeroUsize::new(4).unwrap(),
+            thumbnail_width: 200.0,
+            gap: 16.0,
+            label_font: None,
+            page_fill: Color::WHITE,
+            fill: Color::SILVER,
+        }
+    }
+}
+
+/// Export multiple frames into a grid of thumbnails.
+///
+/// Each frame is scaled to the thumbnail width. The thumbnails are arranged in
+/// rows from left to right and, if a label font is given, labelled with their
+/// page number.
+pub fn render_contact_sheet(frames: &[Frame], sheet: &ContactSheet) -> sk::Pixmap {
+    // Lay out the sheet at one pixel per point.
+    let width = Abs::pt(sheet.thumbnail_width.into());
+    let gap = Abs::pt(sheet.gap.into());
+    let label_size = width / 12.0;
+    let label_height =
+        if sheet.label_font.is_some() { 1.6 * label_size } else { Abs::zero() };
+    let columns = sheet.columns.get();
+
+    let thumbnails: Vec<Frame> = frames
+        .iter()
+        .map(|frame| {
+            let scale =
+                if frame.width() > Abs::zero() { width / frame.width() } else { 1.0 };
+            let mut thumbnail = frame.clone();
+            thumbnail.fill(sheet.page_fill.into());
+            thumbnail.transform(Transform::scale(Ratio::new(scale), Ratio::new(scale)));
+            thumbnail.set_size(frame.size() * scale);
+            thumbnail.clip();
+            thumbnail
+        })
+        .collect();
+
+    let rows: Vec<Abs> = thumbnails
+        .chunks(columns)
+        .map(|row| {
+            row.iter().map(Frame::height).fold(Abs::zero(), Abs::max) + label_height
+        })
+        .collect();
+
+    let size = Size::new(
+        gap + (width + gap) * columns.min(thumbnails.len()).max(1) as f64,
+        gap + rows.iter().map(|&h| h + gap).sum::<Abs>(),
+    );
+
+    let mut canvas = Frame::new(size);
+    let mut y = gap;
+    for (r, (row, &height)) in thumbnails.chunks(columns).zip(&rows).enumerate() {
+        for (c, thumbnail) in row.iter().enumerate() {
+            let x = gap + (width + gap) * c as f64;
+            let bottom = y + thumbnail.height();
+            canvas.push_frame(Point::new(x, y), thumbnail.clone());
+
+            let number = r * columns + c + 1;
+            if let Some(label) = sheet
+                .label_font
+                .as_ref()
+                .and_then(|font| page_label(font, number, label_size))
+            {
+                let pos = Point::new(
+                    x + (width - label.width()) / 2.0,
+                    bottom + 1.2 * label_size,
+                );
+                canvas.push(pos, FrameItem::Text(label));
+            }
+        }
+
+        y += height + gap;
+    }
+
+    render(&canvas, 1.0, sheet.fill)
+}
+
+/// Create a text run with a page number.
+///
+/// The digits are placed without shaping, which is sufficient for numbers in
+/// virtually all fonts. Returns `None` if the font lacks one of the digits.
+fn page_label(font: &Font, number: usize, size: Abs) -> Option<TextItem> {
+    let text = eco_format!("{number}");
+    let mut glyphs = vec![];
+    for (i, c) in text.char_indices() {
+        let id = font.ttf().glyph_index(c)?.0;
+        let start = i as u16;
+        glyphs.push(Glyph {
+            id,
+            x_advance: font.advance(id).unwrap_or_default(),
+            x_offset: Em::zero(),
+            range: start..start + c.len_utf8() as u16,
+            span: (Span::detached(), 0),
+        });
+    }
+
+    Some(TextItem {
+        font: font.clone(),
+        size,
+        fill: Color::BLACK.into(),
+        lang: Lang::ENGLISH,
+        text,
+        glyphs,
+    })
+}
+
+/// Options that control how frames are rasterized.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RenderOptions {
+    /// Whether to smooth the edges of shapes and glyphs. Without
+    /// anti-aliasing, each pixel is either fully covered or not at all.
+    pub anti_alias: bool,
+    /// Whether to snap glyph origins to whole pixels.
+    ///
+    /// At low resolutions, this keeps baselines and stems sharp at the cost of
+    /// slightly uneven letter spacing. Glyph outlines themselves are not
+    /// hinted.
+    pub snap_glyphs: bool,
+    /// The gamma with which glyph coverage is adjusted. Values above one make
+    /// text appear darker and bolder, values below one make it lighter.
+    /// Values that aren't positive and finite are treated like one.
+    pub text_gamma: f32,
+}
+
+impl RenderOptions {
+    /// Adjust the coverage of a glyph pixel according to these options.
+    fn coverage(&self, cov: u8) -> u8 {
+        if !self.anti_alias {
+            return if cov >= 128 { 255 } else { 0 };
+        }
+
+        let gamma = self.text_gamma;
+        let valid = gamma.is_finite() && gamma > 0.0;
+        if !valid || gamma == 1.0 || cov == 0 || cov == 255 {
+            return cov;
+        }
+
+        let adjusted = (cov as f32 / 255.0).powf(1.0 / gamma);
+        (adjusted * 255.0).round() as u8
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            anti_alias: true,
+            snap_glyphs: false,
+            text_gamma: 1.0,
+        }
+    }
+}
+
+impl Hash for RenderOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.anti_alias.hash(state);
+        self.snap_glyphs.hash(state);
+        self.text_gamma.to_bits().hash(state);
+    }
+}
+
 /// Render a frame into the canvas.
 fn render_frame(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     frame: &Frame,
 ) {
     for (pos, item) in frame.items() {
@@ -92,22 +289,23 @@ fn render_frame(
 
         match item {
             FrameItem::Group(group) => {
-                render_group(canvas, ts, mask, group);
+                render_group(canvas, ts, mask, opts, group);
             }
             FrameItem::Text(text) => {
-                render_text(canvas, ts, mask, text);
+                render_text(canvas, ts, mask, opts, text);
             }
             FrameItem::Shape(shape, _) => {
-                render_shape(canvas, ts, mask, shape);
+                render_shape(canvas, ts, mask, opts, shape);
             }
             FrameItem::Image(image, size, _) => {
-                render_image(canvas, ts, mask, image, *size);
+                render_image(canvas, ts, mask, opts, image, *size);
             }
             FrameItem::Meta(meta, _) => match meta {
                 Meta::Link(_) => {}
                 Meta::Elem(_) => {}
                 Meta::PageNumbering(_) => {}
                 Meta::Hide => {}
+                Meta::Line => {}
             },
         }
     }
@@ -118,6 +316,7 @@ fn render_group(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     group: &GroupItem,
 ) {
     let ts = ts.pre_concat(group.transform.into());
@@ -163,7 +362,25 @@ fn render_group(
         }
     }
 
-    render_frame(canvas, ts, mask, &group.frame);
+    if group.opacity != Ratio::one() {
+        // Render the contents into a separate layer so that they are
+        // composited as a whole.
+        let Some(mut layer) = sk::Pixmap::new(canvas.width(), canvas.height()) else {
+            return;
+        };
+
+        render_frame(&mut layer, ts, None, opts, &group.frame);
+
+        let paint = sk::PixmapPaint {
+            opacity: group.opacity.get() as f32,
+            ..Default::default()
+        };
+
+        canvas.draw_pixmap(0, 0, layer.as_ref(), &paint, sk::Transform::identity(), mask);
+        return;
+    }
+
+    render_frame(canvas, ts, mask, opts, &group.frame);
 }
 
 /// Render a text run into the canvas.
@@ -171,27 +388,65 @@ fn render_text(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     text: &TextItem,
 ) {
+    if !matches!(text.fill, Paint::Solid(_)) {
+        render_painted_text(canvas, ts, mask, opts, text);
+        return;
+    }
+
     let mut x = 0.0;
     for glyph in &text.glyphs {
         let id = GlyphId(glyph.id);
         let offset = x + glyph.x_offset.at(text.size).to_f32();
         let ts = ts.pre_translate(offset, 0.0);
 
-        render_svg_glyph(canvas, ts, mask, text, id)
-            .or_else(|| render_bitmap_glyph(canvas, ts, mask, text, id))
-            .or_else(|| render_outline_glyph(canvas, ts, mask, text, id));
+        render_svg_glyph(canvas, ts, mask, opts, text, id)
+            .or_else(|| render_bitmap_glyph(canvas, ts, mask, opts, text, id))
+            .or_else(|| render_outline_glyph(canvas, ts, mask, opts, text, id));
 
         x += glyph.x_advance.at(text.size).to_f32();
     }
 }
 
+/// Render a text run whose fill is a gradient or pattern.
+///
+/// All glyph outlines are filled at once so that the paint spans the whole
+/// run instead of restarting for each glyph. Color and bitmap glyphs are not
+/// supported here.
+fn render_painted_text(
+    canvas: &mut sk::Pixmap,
+    ts: sk::Transform,
+    mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
+    text: &TextItem,
+) -> Option<()> {
+    let scale = text.size.to_f32() / text.font.units_per_em() as f32;
+    let mut builder =
+        PlacedPathBuilder { builder: sk::PathBuilder::new(), x: 0.0, scale };
+
+    let mut x = 0.0;
+    for glyph in &text.glyphs {
+        builder.x = x + glyph.x_offset.at(text.size).to_f32();
+        text.font.ttf().outline_glyph(GlyphId(glyph.id), &mut builder);
+        x += glyph.x_advance.at(text.size).to_f32();
+    }
+
+    let path = builder.builder.finish()?;
+    let (origin, size) = text.bbox();
+    let mut tile = None;
+    let paint = to_sk_paint(&text.fill, origin, size, ts, opts, &mut tile);
+    canvas.fill_path(&path, &paint, sk::FillRule::default(), ts, mask);
+    Some(())
+}
+
 /// Render an SVG glyph into the canvas.
 fn render_svg_glyph(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     text: &TextItem,
     id: GlyphId,
 ) -> Option<()> {
@@ -211,8 +466,11 @@ fn render_svg_glyph(
     let root = document.root_element();
 
     // Parse SVG.
-    let opts = usvg::Options::default();
-    let tree = usvg::Tree::from_xmltree(&document, &opts).ok()?;
+    let mut svg_opts = usvg::Options::default();
+    if !opts.anti_alias {
+        svg_opts.shape_rendering = usvg::ShapeRendering::CrispEdges;
+    }
+    let tree = usvg::Tree::from_xmltree(&document, &svg_opts).ok()?;
     let view_box = tree.view_box.rect;
 
     // If there's no viewbox defined, use the em square for our scale
@@ -289,6 +547,7 @@ fn render_bitmap_glyph(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     text: &TextItem,
     id: GlyphId,
 ) -> Option<()> {
@@ -305,14 +564,15 @@ fn render_bitmap_glyph(
     let dx = (raster.x as f32) / (image.width() as f32) * size;
     let dy = (raster.y as f32) / (image.height() as f32) * size;
     let ts = ts.pre_translate(dx, -size - dy);
-    render_image(canvas, ts, mask, &image, Size::new(w, h))
+    render_image(canvas, ts, mask, opts, &image, Size::new(w, h))
 }
 
 /// Render an outline glyph into the canvas. This is the "normal" case.
 fn render_outline_glyph(
     canvas: &mut sk::Pixmap,
-    ts: sk::Transform,
+    mut ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     text: &TextItem,
     id: GlyphId,
 ) -> Option<()> {
@@ -328,7 +588,9 @@ fn render_outline_glyph(
             builder.0.finish()?
         };
 
-        let paint = (&text.fill).into();
+        let mut tile = None;
+        let paint =
+            to_sk_paint(&text.fill, Point::zero(), Size::zero(), ts, opts, &mut tile);
         let rule = sk::FillRule::default();
 
         // Flip vertically because font design coordinate
@@ -356,6 +618,11 @@ fn render_outline_glyph(
         )))
     }
 
+    if opts.snap_glyphs {
+        ts.tx = ts.tx.round();
+        ts.ty = ts.ty.round();
+    }
+
     // Try to retrieve a prepared glyph or prepare it from scratch if it
     // doesn't exist, yet.
     let bitmap =
@@ -367,15 +634,14 @@ fn render_outline_glyph(
         let mw = bitmap.width;
         let mh = bitmap.height;
 
-        let Paint::Solid(color) = text.fill;
-        let c = color.to_rgba();
+        let c = text.fill.to_color().to_rgba();
 
         // Pad the pixmap with 1 pixel in each dimension so that we do
         // not get any problem with floating point errors along their border
         let mut pixmap = sk::Pixmap::new(mw + 2, mh + 2)?;
         for x in 0..mw {
             for y in 0..mh {
-                let alpha = bitmap.coverage[(y * mw + x) as usize];
+                let alpha = opts.coverage(bitmap.coverage[(y * mw + x) as usize]);
                 let color = sk::ColorU8::from_rgba(c.r, c.g, c.b, alpha).premultiply();
                 pixmap.pixels_mut()[((y + 1) * (mw + 2) + (x + 1)) as usize] = color;
             }
@@ -405,8 +671,7 @@ fn render_outline_glyph(
         let bottom = top + mh;
 
         // Premultiply the text color.
-        let Paint::Solid(color) = text.fill;
-        let c = color.to_rgba();
+        let c = text.fill.to_color().to_rgba();
         let color = sk::ColorU8::from_rgba(c.r, c.g, c.b, 255).premultiply().get();
 
         // Blend the glyph bitmap with the existing pixels on the canvas.
@@ -414,7 +679,7 @@ fn render_outline_glyph(
         for x in left.clamp(0, cw)..right.clamp(0, cw) {
             for y in top.clamp(0, ch)..bottom.clamp(0, ch) {
                 let ai = ((y - top) * mw + (x - left)) as usize;
-                let cov = bitmap.coverage[ai];
+                let cov = opts.coverage(bitmap.coverage[ai]);
                 if cov == 0 {
                     continue;
                 }
@@ -439,6 +704,7 @@ fn render_shape(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     shape: &Shape,
 ) -> Option<()> {
     let path = match shape.geometry {
@@ -456,8 +722,10 @@ fn render_shape(
         Geometry::Path(ref path) => convert_path(path)?,
     };
 
+    let bbox = shape.geometry.bbox_size();
     if let Some(fill) = &shape.fill {
-        let mut paint: sk::Paint = fill.into();
+        let mut tile = None;
+        let mut paint = to_sk_paint(fill, Point::zero(), bbox, ts, opts, &mut tile);
         if matches!(shape.geometry, Geometry::Rect(_)) {
             paint.anti_alias = false;
         }
@@ -490,7 +758,8 @@ fn render_shape(
 
                 sk::StrokeDash::new(dash_array, pattern.phase.to_f32())
             });
-            let paint = paint.into();
+            let mut tile = None;
+            let paint = to_sk_paint(paint, Point::zero(), bbox, ts, opts, &mut tile);
             let stroke = sk::Stroke {
                 width,
                 line_cap: line_cap.into(),
@@ -539,12 +808,24 @@ fn render_image(
     canvas: &mut sk::Pixmap,
     ts: sk::Transform,
     mask: Option<&sk::Mask>,
+    opts: &RenderOptions,
     image: &Image,
     size: Size,
 ) -> Option<()> {
     let view_width = size.x.to_f32();
     let view_height = size.y.to_f32();
 
+    // There is no PDF rasterizer, so embedded PDF pages are replaced with a
+    // gray placeholder.
+    if let DecodedImage::Pdf(_) = image.decoded().as_ref() {
+        let rect = sk::Rect::from_xywh(0.0, 0.0, view_width, view_height)?;
+        let mut paint = sk::Paint::default();
+        paint.set_color(Color::SILVER.into());
+        paint.anti_alias = opts.anti_alias;
+        canvas.fill_rect(rect, &paint, ts, mask);
+        return Some(());
+    }
+
     // For better-looking output, resize `image` to its final size before
     // painting it to `canvas`. For the math, see:
     // https://github.com/typst/typst/issues/1404#issuecomment-1598374652
@@ -572,6 +853,7 @@ fn render_image(
             1.0,
             sk::Transform::from_scale(paint_scale_x, paint_scale_y),
         ),
+        anti_alias: opts.anti_alias,
         ..Default::default()
     };
 
@@ -604,6 +886,8 @@ fn scaled_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
                 pixmap.as_mut(),
             )?;
         }
+        // Embedded PDF pages are drawn as placeholders by `render_image`.
+        DecodedImage::Pdf(_) => return None,
     }
     Some(Arc::new(pixmap))
 }
@@ -622,14 +906,105 @@ impl From<Transform> for sk::Transform {
     }
 }
 
-impl From<&Paint> for sk::Paint<'static> {
-    fn from(paint: &Paint) -> Self {
-        let mut sk_paint = sk::Paint::default();
-        let Paint::Solid(color) = *paint;
-        sk_paint.set_color(color.into());
-        sk_paint.anti_alias = true;
-        sk_paint
+/// Convert a Typst paint into a tiny-skia paint for an item whose bounding box
+/// has the given origin and size.
+///
+/// The transform is the one the item is drawn with. A pattern's tile is
+/// rasterized at its scale and kept in `tile`, which the paint borrows from.
+fn to_sk_paint<'a>(
+    paint: &Paint,
+    origin: Point,
+    size: Size,
+    ts: sk::Transform,
+    opts: &RenderOptions,
+    tile: &'a mut Option<Arc<sk::Pixmap>>,
+) -> sk::Paint<'a> {
+    let mut sk_paint = match paint {
+        Paint::Solid(color) => {
+            let mut sk_paint = sk::Paint::default();
+            sk_paint.set_color((*color).into());
+            sk_paint
+        }
+        Paint::Gradient(gradient) => gradient_paint(gradient, origin, size),
+        Paint::Pattern(pattern) => {
+            let scale = ts.sx.hypot(ts.ky).max(ts.kx.hypot(ts.sy));
+            let pixmap: &sk::Pixmap =
+                tile.insert(render_tile(pattern, scale.to_bits(), *opts));
+
+            // Map the tile's pixels back onto its period in the item's
+            // coordinate system.
+            let period = pattern.period();
+            let transform =
+                sk::Transform::from_translate(origin.x.to_f32(), origin.y.to_f32())
+                    .pre_scale(
+                        period.x.to_f32() / pixmap.width() as f32,
+                        period.y.to_f32() / pixmap.height() as f32,
+                    );
+
+            let mut sk_paint = sk::Paint::default();
+            sk_paint.shader = sk::Pattern::new(
+                pixmap.as_ref(),
+                sk::SpreadMode::Repeat,
+                sk::FilterQuality::Bilinear,
+                1.0,
+                transform,
+            );
+            sk_paint
+        }
+    };
+
+    sk_paint.anti_alias = opts.anti_alias;
+    sk_paint
+}
+
+/// Rasterize a pattern's tile, including the spacing after it, at the given
+/// number of pixels per point.
+#[comemo::memoize]
+fn render_tile(pattern: &Pattern, scale: u32, opts: RenderOptions) -> Arc<sk::Pixmap> {
+    let scale = f32::from_bits(scale);
+    let period = pattern.period();
+    let pxw = (scale * period.x.to_f32()).round().max(1.0) as u32;
+    let pxh = (scale * period.y.to_f32()).round().max(1.0) as u32;
+
+    // Scale exactly to the rounded pixel size so that the tiles line up.
+    let mut pixmap = sk::Pixmap::new(pxw, pxh).unwrap();
+    let ts = sk::Transform::from_scale(
+        pxw as f32 / period.x.to_f32(),
+        pxh as f32 / period.y.to_f32(),
+    );
+    render_frame(&mut pixmap, ts, None, &opts, pattern.frame());
+
+    Arc::new(pixmap)
+}
+
+/// Create a tiny-skia paint for a gradient spanning the box with the given
+/// origin and size.
+fn gradient_paint(gradient: &Gradient, origin: Point, size: Size) -> sk::Paint<'static> {
+    let (start, end) = gradient.axis(size);
+    let point = |p: Point| {
+        sk::Point::from_xy((origin.x + p.x).to_f32(), (origin.y + p.y).to_f32())
+    };
+
+    let stops = gradient
+        .stops()
+        .iter()
+        .map(|&(color, offset)| sk::GradientStop::new(offset.get() as f32, color.into()))
+        .collect();
+
+    let mut sk_paint = sk::Paint::default();
+    match sk::LinearGradient::new(
+        point(start),
+        point(end),
+        stops,
+        sk::SpreadMode::Pad,
+        sk::Transform::identity(),
+    ) {
+        Some(shader) => sk_paint.shader = shader,
+        // Degenerate gradients, e.g. for empty boxes, can't be created.
+        None => sk_paint.set_color(gradient.sample(0.5).into()),
     }
+
+    sk_paint
 }
 
 impl From<Color> for sk::Color {
@@ -684,6 +1059,49 @@ impl OutlineBuilder for WrappedPathBuilder {
     }
 }
 
+/// Builds a single tiny-skia path from multiple glyph outlines, placing each
+/// at its horizontal offset and flipping it into the Y-down coordinate system.
+struct PlacedPathBuilder {
+    builder: sk::PathBuilder,
+    x: f32,
+    scale: f32,
+}
+
+impl PlacedPathBuilder {
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.x + x * self.scale, -y * self.scale)
+    }
+}
+
+impl OutlineBuilder for PlacedPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x, y) = self.point(x, y);
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x2, y2) = self.point(x2, y2);
+        let (x, y) = self.point(x, y);
+        self.builder.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
 /// Additional methods for [`Length`].
 trait AbsExt {
     /// Convert to a number of points as f32.
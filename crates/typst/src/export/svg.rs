@@ -10,8 +10,8 @@ use xmlwriter::XmlWriter;
 use crate::doc::{Frame, FrameItem, GroupItem, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    Abs, Axes, Geometry, LineCap, LineJoin, Paint, PathItem, Ratio, Shape, Size, Stroke,
-    Transform,
+    Abs, Axes, Geometry, Gradient, LineCap, LineJoin, Paint, PathItem, Pattern, Point,
+    Ratio, Shape, Size, Stroke, Transform,
 };
 use crate::image::{Image, ImageFormat, RasterFormat, VectorFormat};
 use crate::util::hash128;
@@ -58,6 +58,13 @@ struct SVGRenderer {
     /// attribute of the group. The clip path is in the format of `M x y L x y C
     /// x1 y1 x2 y2 x y Z`.
     clip_paths: Deduplicator<EcoString>,
+    /// Gradients used by fills and strokes. Each item that uses a gradient
+    /// gets its own definition because gradients are positioned in the user
+    /// space of the item.
+    gradients: Deduplicator<RenderedGradient>,
+    /// Patterns used by fills and strokes. Like gradients, they are defined
+    /// per item because their tiles start at the item's origin.
+    patterns: Deduplicator<RenderedPattern>,
 }
 
 /// Represents a glyph to be rendered.
@@ -72,6 +79,30 @@ enum RenderedGlyph {
     Image { url: EcoString, width: f64, height: f64, ts: Transform },
 }
 
+/// A pattern positioned for a specific item.
+#[derive(Clone, Hash)]
+struct RenderedPattern {
+    /// The pattern itself.
+    pattern: Pattern,
+    /// The transform from the pattern's coordinates into the item's user
+    /// space.
+    ts: Transform,
+}
+
+/// A gradient positioned for a specific item.
+#[derive(Hash)]
+struct RenderedGradient {
+    /// The gradient itself.
+    gradient: Gradient,
+    /// The start of the gradient's axis.
+    start: Point,
+    /// The end of the gradient's axis.
+    end: Point,
+    /// The transform from the gradient's coordinates into the item's user
+    /// space.
+    ts: Transform,
+}
+
 impl SVGRenderer {
     /// Create a new SVG renderer with empty glyph and clip path.
     fn new() -> Self {
@@ -79,6 +110,8 @@ impl SVGRenderer {
             xml: XmlWriter::new(xmlwriter::Options::default()),
             glyphs: Deduplicator::new('g'),
             clip_paths: Deduplicator::new('c'),
+            gradients: Deduplicator::new('f'),
+            patterns: Deduplicator::new('p'),
         }
     }
 
@@ -146,6 +179,10 @@ impl SVGRenderer {
             self.xml.write_attribute_fmt("clip-path", format_args!("url(#{id})"));
         }
 
+        if group.opacity != Ratio::one() {
+            self.xml.write_attribute("opacity", &group.opacity.get());
+        }
+
         self.render_frame(&group.frame, group.transform);
         self.xml.end_element();
     }
@@ -171,7 +208,9 @@ impl SVGRenderer {
 
             self.render_svg_glyph(text, id, offset, inv_scale)
                 .or_else(|| self.render_bitmap_glyph(text, id, offset, inv_scale))
-                .or_else(|| self.render_outline_glyph(text, id, offset, inv_scale));
+                .or_else(|| {
+                    self.render_outline_glyph(text, id, offset, inv_scale, scale)
+                });
 
             x += glyph.x_advance.at(text.size).to_pt();
         }
@@ -261,16 +300,31 @@ impl SVGRenderer {
         id: GlyphId,
         x_offset: f64,
         inv_scale: f64,
+        scale: f64,
     ) -> Option<()> {
         let path = convert_outline_glyph_to_path(&text.font, id)?;
         let hash = hash128(&(&text.font, id));
         let id = self.glyphs.insert_with(hash, || RenderedGlyph::Path(path));
 
+        let x = x_offset * inv_scale;
         self.xml.start_element("use");
         self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
-        self.xml
-            .write_attribute_fmt("x", format_args!("{}", x_offset * inv_scale));
-        self.write_fill(&text.fill);
+        self.xml.write_attribute_fmt("x", format_args!("{}", x));
+
+        // The glyph lives in the scaled and flipped coordinate system of the
+        // text group, shifted by its offset, but a gradient should span the
+        // whole text run. Thus, we map the gradient from the run's coordinates
+        // into the glyph's.
+        let (origin, size) = text.bbox();
+        let ts = Transform {
+            sx: Ratio::new(1.0 / scale),
+            ky: Ratio::zero(),
+            kx: Ratio::zero(),
+            sy: Ratio::new(-1.0 / scale),
+            tx: Abs::pt(-x),
+            ty: Abs::zero(),
+        };
+        self.write_fill(&text.fill, origin, size, ts);
         self.xml.end_element();
 
         Some(())
@@ -281,14 +335,15 @@ impl SVGRenderer {
         self.xml.start_element("path");
         self.xml.write_attribute("class", "typst-shape");
 
+        let size = shape.geometry.bbox_size();
         if let Some(paint) = &shape.fill {
-            self.write_fill(paint);
+            self.write_fill(paint, Point::zero(), size, Transform::identity());
         } else {
             self.xml.write_attribute("fill", "none");
         }
 
         if let Some(stroke) = &shape.stroke {
-            self.write_stroke(stroke);
+            self.write_stroke(stroke, size);
         }
 
         let path = convert_geometry_to_path(&shape.geometry);
@@ -296,16 +351,23 @@ impl SVGRenderer {
         self.xml.end_element();
     }
 
-    /// Write a fill attribute.
-    fn write_fill(&mut self, fill: &Paint) {
-        let Paint::Solid(color) = fill;
-        self.xml.write_attribute("fill", &color.to_rgba().to_hex());
+    /// Write a fill attribute for an item with the given bounding box.
+    ///
+    /// The transform maps from the bounding box's coordinate system into the
+    /// item's user space.
+    fn write_fill(&mut self, fill: &Paint, origin: Point, size: Size, ts: Transform) {
+        self.write_paint("fill", fill, origin, size, ts);
     }
 
-    /// Write a stroke attribute.
-    fn write_stroke(&mut self, stroke: &Stroke) {
-        let Paint::Solid(color) = stroke.paint;
-        self.xml.write_attribute("stroke", &color.to_rgba().to_hex());
+    /// Write a stroke attribute for an item with the given bounding box size.
+    fn write_stroke(&mut self, stroke: &Stroke, size: Size) {
+        self.write_paint(
+            "stroke",
+            &stroke.paint,
+            Point::zero(),
+            size,
+            Transform::identity(),
+        );
         self.xml.write_attribute("stroke-width", &stroke.thickness.to_pt());
         self.xml.write_attribute(
             "stroke-linecap",
@@ -338,8 +400,57 @@ impl SVGRenderer {
         }
     }
 
+    /// Write a paint as the given attribute, creating a gradient or pattern
+    /// definition if necessary.
+    fn write_paint(
+        &mut self,
+        attr: &str,
+        paint: &Paint,
+        origin: Point,
+        size: Size,
+        ts: Transform,
+    ) {
+        match paint {
+            Paint::Solid(color) => {
+                self.xml.write_attribute(attr, &color.to_rgba().to_hex());
+            }
+            Paint::Gradient(gradient) => {
+                let (start, end) = gradient.axis(size);
+                let rendered = RenderedGradient {
+                    gradient: gradient.clone(),
+                    start: origin + start,
+                    end: origin + end,
+                    ts,
+                };
+                let hash = hash128(&rendered);
+                let id = self.gradients.insert_with(hash, || rendered);
+                self.xml.write_attribute_fmt(attr, format_args!("url(#{id})"));
+            }
+            Paint::Pattern(pattern) => {
+                let rendered = RenderedPattern {
+                    pattern: pattern.clone(),
+                    ts: ts.pre_concat(Transform::translate(origin.x, origin.y)),
+                };
+                let hash = hash128(&rendered);
+                let id = self.patterns.insert_with(hash, || rendered);
+                self.xml.write_attribute_fmt(attr, format_args!("url(#{id})"));
+            }
+        }
+    }
+
     /// Render an image element.
     fn render_image(&mut self, image: &Image, size: &Axes<Abs>) {
+        // SVG viewers can't display embedded PDF pages, so they are replaced
+        // with a gray placeholder.
+        if image.format() == ImageFormat::Vector(VectorFormat::Pdf) {
+            self.xml.start_element("rect");
+            self.xml.write_attribute("width", &size.x.to_pt());
+            self.xml.write_attribute("height", &size.y.to_pt());
+            self.xml.write_attribute("fill", "#dddddd");
+            self.xml.end_element();
+            return;
+        }
+
         let url = convert_image_to_base64_url(image);
         self.xml.start_element("image");
         self.xml.write_attribute("xlink:href", &url);
@@ -351,8 +462,11 @@ impl SVGRenderer {
 
     /// Finalize the SVG file. This must be called after all rendering is done.
     fn finalize(mut self) -> String {
+        // Patterns go first because their tiles may add further definitions.
+        self.write_pattern_defs();
         self.write_glyph_defs();
         self.write_clip_path_defs();
+        self.write_gradient_defs();
         self.xml.end_document()
     }
 
@@ -407,6 +521,64 @@ impl SVGRenderer {
 
         self.xml.end_element();
     }
+
+    /// Build the pattern definitions.
+    fn write_pattern_defs(&mut self) {
+        self.xml.start_element("defs");
+        self.xml.write_attribute("id", "patterns");
+
+        // Rendering a tile may register further patterns, so we can't just
+        // iterate over them.
+        let mut i = 0;
+        while let Some(rendered) = self.patterns.vec.get(i).cloned() {
+            let id = Id(self.patterns.kind, i);
+            let period = rendered.pattern.period();
+            self.xml.start_element("pattern");
+            self.xml.write_attribute("id", &id);
+            self.xml.write_attribute("patternUnits", "userSpaceOnUse");
+            self.xml.write_attribute("width", &period.x.to_pt());
+            self.xml.write_attribute("height", &period.y.to_pt());
+            if !rendered.ts.is_identity() {
+                self.xml.write_attribute("patternTransform", &SvgMatrix(rendered.ts));
+            }
+
+            self.render_frame(rendered.pattern.frame(), Transform::identity());
+            self.xml.end_element();
+            i += 1;
+        }
+
+        self.xml.end_element();
+    }
+
+    /// Build the gradient definitions.
+    fn write_gradient_defs(&mut self) {
+        self.xml.start_element("defs");
+        self.xml.write_attribute("id", "gradients");
+
+        for (id, rendered) in self.gradients.iter() {
+            self.xml.start_element("linearGradient");
+            self.xml.write_attribute("id", &id);
+            self.xml.write_attribute("gradientUnits", "userSpaceOnUse");
+            self.xml.write_attribute("x1", &rendered.start.x.to_pt());
+            self.xml.write_attribute("y1", &rendered.start.y.to_pt());
+            self.xml.write_attribute("x2", &rendered.end.x.to_pt());
+            self.xml.write_attribute("y2", &rendered.end.y.to_pt());
+            if !rendered.ts.is_identity() {
+                self.xml.write_attribute("gradientTransform", &SvgMatrix(rendered.ts));
+            }
+
+            for (color, offset) in rendered.gradient.stops() {
+                self.xml.start_element("stop");
+                self.xml.write_attribute("offset", &offset.get());
+                self.xml.write_attribute("stop-color", &color.to_rgba().to_hex());
+                self.xml.end_element();
+            }
+
+            self.xml.end_element();
+        }
+
+        self.xml.end_element();
+    }
 }
 
 /// Convert an outline glyph to an SVG path.
@@ -537,6 +709,7 @@ fn convert_image_to_base64_url(image: &Image) -> EcoString {
         },
         ImageFormat::Vector(f) => match f {
             VectorFormat::Svg => "svg+xml",
+            VectorFormat::Pdf => "pdf",
         },
     };
 
@@ -14,7 +14,7 @@ use ttf_parser::GlyphId;
 
 use self::book::find_name;
 use crate::eval::{Bytes, Cast};
-use crate::geom::Em;
+use crate::geom::{Abs, Em, Path, Point};
 
 /// An OpenType font.
 ///
@@ -104,6 +104,19 @@ impl Font {
             .map(|units| self.to_em(units))
     }
 
+    /// Look up the outline of a glyph, scaled to the given font size.
+    ///
+    /// The path's origin is the glyph's origin on the baseline and its y-axis
+    /// points downwards, like everywhere else in a frame. Returns `None` for
+    /// glyphs without an outline, like spaces or bitmap glyphs.
+    pub fn outline(&self, glyph: u16, size: Abs) -> Option<Path> {
+        let scale = size / Abs::raw(self.units_per_em());
+        let mut builder =
+            GlyphPathBuilder { path: Path::new(), scale, last: Point::zero() };
+        self.0.ttf.outline_glyph(GlyphId(glyph), &mut builder)?;
+        Some(builder.path)
+    }
+
     /// Lookup a name by id.
     pub fn find_name(&self, id: u16) -> Option<String> {
         find_name(&self.0.ttf, id)
@@ -124,6 +137,50 @@ impl Font {
     }
 }
 
+/// Builds a frame path from a glyph outline in font units.
+struct GlyphPathBuilder {
+    path: Path,
+    scale: f64,
+    last: Point,
+}
+
+impl GlyphPathBuilder {
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(Abs::raw(x as f64 * self.scale), Abs::raw(-y as f64 * self.scale))
+    }
+}
+
+impl ttf_parser::OutlineBuilder for GlyphPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.last = self.point(x, y);
+        self.path.move_to(self.last);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.last = self.point(x, y);
+        self.path.line_to(self.last);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        // Elevate the quadratic curve to a cubic one.
+        let control = self.point(x1, y1);
+        let end = self.point(x, y);
+        let p1 = self.last + (control - self.last) * (2.0 / 3.0);
+        let p2 = end + (control - end) * (2.0 / 3.0);
+        self.path.cubic_to(p1, p2, end);
+        self.last = end;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.last = self.point(x, y);
+        self.path.cubic_to(self.point(x1, y1), self.point(x2, y2), self.last);
+    }
+
+    fn close(&mut self) {
+        self.path.close_path();
+    }
+}
+
 impl Hash for Font {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.data.hash(state);
@@ -72,6 +72,40 @@ impl Color {
         }
     }
 
+    /// Increase the saturation of this color by the given factor.
+    ///
+    /// Works in the perceptual Oklab space by scaling the chroma. Gray colors
+    /// have no chroma and are returned unchanged; CMYK colors are converted to
+    /// RGBA. Fails if the factor is negative.
+    pub fn saturate(self, factor: Ratio) -> StrResult<Self> {
+        if factor.get() < 0.0 {
+            bail!("factor must not be negative");
+        }
+        Ok(self.scale_chroma(1.0 + factor.get() as f32))
+    }
+
+    /// Decrease the saturation of this color by the given factor.
+    ///
+    /// See [`saturate`](Self::saturate) for details.
+    pub fn desaturate(self, factor: Ratio) -> StrResult<Self> {
+        if factor.get() < 0.0 {
+            bail!("factor must not be negative");
+        }
+        Ok(self.scale_chroma((1.0 - factor.get() as f32).max(0.0)))
+    }
+
+    /// Scale the chroma of this color in Oklab space.
+    fn scale_chroma(self, scale: f32) -> Self {
+        match self {
+            Self::Luma(_) => self,
+            Self::Rgba(_) | Self::Cmyk(_) => {
+                let [l, a, b, alpha] = rgba_to_vec4(self.to_rgba(), ColorSpace::Oklab);
+                let scaled = [l, a * scale, b * scale, alpha];
+                Self::Rgba(vec4_to_rgba(scaled, ColorSpace::Oklab))
+            }
+        }
+    }
+
     /// Mixes multiple colors through weight.
     pub fn mix(
         colors: impl IntoIterator<Item = WeightedColor>,
@@ -34,6 +34,12 @@ impl<T> Corners<T> {
         }
     }
 
+    /// Replace the component for one corner.
+    pub fn with(mut self, corner: Corner, value: T) -> Self {
+        self.set(corner, value);
+        self
+    }
+
     /// Map the individual fields with `f`.
     pub fn map<F, U>(self, mut f: F) -> Corners<U>
     where
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use super::*;
+
+/// A color gradient.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub enum Gradient {
+    /// A gradient that interpolates between colors along a straight line.
+    Linear(Arc<LinearGradient>),
+}
+
+/// The data of a linear gradient.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LinearGradient {
+    /// The color stops with their offsets, in ascending order.
+    pub stops: Vec<(Color, Ratio)>,
+    /// The direction of the gradient.
+    pub angle: Angle,
+}
+
+impl Gradient {
+    /// Create a new linear gradient.
+    ///
+    /// Stops without an explicit offset are distributed evenly.
+    pub fn linear(stops: Vec<GradientStop>, angle: Angle) -> StrResult<Self> {
+        if stops.len() < 2 {
+            bail!("a gradient must have at least two stops");
+        }
+
+        let stops = resolve_stops(stops)?;
+        Ok(Self::Linear(Arc::new(LinearGradient { stops, angle })))
+    }
+
+    /// The color stops with their offsets, in ascending order.
+    pub fn stops(&self) -> &[(Color, Ratio)] {
+        match self {
+            Self::Linear(linear) => &linear.stops,
+        }
+    }
+
+    /// The direction of the gradient.
+    pub fn angle(&self) -> Angle {
+        match self {
+            Self::Linear(linear) => linear.angle,
+        }
+    }
+
+    /// Sample the gradient's color at a position between zero and one.
+    ///
+    /// Interpolation happens in sRGB, just like in the exporters.
+    pub fn sample(&self, t: f64) -> Color {
+        let stops = self.stops();
+        let t = t.clamp(0.0, 1.0);
+        let i = stops
+            .iter()
+            .position(|&(_, offset)| offset.get() >= t)
+            .unwrap_or(stops.len() - 1);
+
+        if i == 0 {
+            return stops[0].0;
+        }
+
+        let (c0, o0) = stops[i - 1];
+        let (c1, o1) = stops[i];
+        let span = o1.get() - o0.get();
+        let w = if span > 0.0 { (t - o0.get()) / span } else { 1.0 };
+
+        let (a, b) = (c0.to_rgba(), c1.to_rgba());
+        let lerp = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * w).round() as u8;
+        RgbaColor::new(lerp(a.r, b.r), lerp(a.g, b.g), lerp(a.b, b.b), lerp(a.a, b.a))
+            .into()
+    }
+
+    /// The start and end point of the gradient's axis when it spans a box of
+    /// the given size with its origin in the top-left corner.
+    ///
+    /// The axis passes through the center of the box and is long enough for
+    /// the first and last stop to touch the box's corners.
+    pub fn axis(&self, size: Size) -> (Point, Point) {
+        let (sin, cos) = self.angle().to_rad().sin_cos();
+        let half = (size.x * cos.abs() + size.y * sin.abs()) / 2.0;
+        let center = size.to_point() / 2.0;
+        let delta = Point::new(half * cos, half * sin);
+        (center - delta, center + delta)
+    }
+}
+
+impl Debug for Gradient {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Linear(linear) => {
+                f.write_str("gradient.linear(")?;
+                for (color, offset) in &linear.stops {
+                    write!(f, "({color:?}, {offset:?}), ")?;
+                }
+                write!(f, "angle: {:?})", linear.angle)
+            }
+        }
+    }
+}
+
+cast! {
+    type Gradient: "gradient",
+}
+
+/// A color stop with an optional offset.
+pub struct GradientStop(Color, Option<Ratio>);
+
+cast! {
+    GradientStop,
+    v: Color => Self(v, None),
+    v: Array => {
+        let mut iter = v.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(c), Some(o), None) => Self(c.cast()?, Some(o.cast()?)),
+            _ => bail!("expected a color or color-offset pair"),
+        }
+    }
+}
+
+/// Assign offsets to stops that lack them and check that all offsets are in
+/// ascending order.
+fn resolve_stops(stops: Vec<GradientStop>) -> StrResult<Vec<(Color, Ratio)>> {
+    let last = stops.len() - 1;
+    let mut prev = 0.0;
+    let mut resolved = Vec::with_capacity(stops.len());
+
+    for (i, GradientStop(color, offset)) in stops.into_iter().enumerate() {
+        let offset = match offset {
+            Some(offset) => {
+                if !(0.0..=1.0).contains(&offset.get()) {
+                    bail!("offset must be between 0% and 100%");
+                } else if offset.get() < prev {
+                    bail!("offsets must be in ascending order");
+                }
+                offset
+            }
+            None => Ratio::new((i as f64 / last as f64).max(prev)),
+        };
+
+        prev = offset.get();
+        resolved.push((color, offset));
+    }
+
+    Ok(resolved)
+}
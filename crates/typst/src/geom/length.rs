@@ -18,6 +18,19 @@ impl Length {
         Self { abs: Abs::zero(), em: Em::zero() }
     }
 
+    /// Create a new length from its parts.
+    pub const fn new(abs: Abs, em: Em) -> Self {
+        Self { abs, em }
+    }
+
+    /// Convert to an absolute length at the given font size.
+    ///
+    /// This is what [resolving](Resolve) does with the font size of the active
+    /// styles.
+    pub fn at(self, font_size: Abs) -> Abs {
+        self.abs + self.em.at(font_size)
+    }
+
     /// Try to compute the absolute value of the length.
     pub fn try_abs(self) -> Option<Self> {
         (self.abs.is_zero() || self.em.is_zero())
@@ -1,4 +1,19 @@
 //! Geometrical primitives.
+//!
+//! Lengths are modelled in layers:
+//! - [`Abs`] is an absolute length in points.
+//! - [`Em`] is a length relative to the font size.
+//! - [`Length`] combines an absolute and a font-relative part.
+//! - [`Ratio`] is a ratio of some whole, like the width of a region.
+//! - [`Rel`] combines a ratio with an absolute part, e.g. `Rel<Length>` for
+//!   `50% + 1em` or `Rel<Abs>` for `50% + 2pt`.
+//! - [`Fr`] is a fraction of the remaining space.
+//!
+//! Font-relative parts are turned into absolute ones by [resolving](Resolve)
+//! with the active styles or, outside of layout, with [`Length::at`] and
+//! [`Rel::at`]. Relative parts are then applied to a concrete whole with
+//! [`Rel::relative_to`] and fractions are distributed with [`Fr::share`]. All
+//! of these can be combined with [`Sides`], [`Corners`], and [`Axes`].
 
 #[macro_use]
 mod macros;
@@ -12,9 +27,11 @@ mod dir;
 mod ellipse;
 mod em;
 mod fr;
+mod gradient;
 mod length;
 mod paint;
 mod path;
+mod pattern;
 mod point;
 mod ratio;
 mod rel;
@@ -39,9 +56,11 @@ pub use self::dir::Dir;
 pub use self::ellipse::ellipse;
 pub use self::em::Em;
 pub use self::fr::Fr;
+pub use self::gradient::{Gradient, GradientStop, LinearGradient};
 pub use self::length::Length;
 pub use self::paint::Paint;
 pub use self::path::{Path, PathItem};
+pub use self::pattern::Pattern;
 pub use self::point::Point;
 pub use self::ratio::Ratio;
 pub use self::rel::Rel;
@@ -5,6 +5,25 @@ use super::*;
 pub enum Paint {
     /// A solid color.
     Solid(Color),
+    /// A gradient.
+    Gradient(Gradient),
+    /// A repeating pattern.
+    Pattern(Pattern),
+}
+
+impl Paint {
+    /// A single color that stands in for this paint.
+    ///
+    /// For gradients, this is the color in the middle of the gradient and
+    /// patterns fall back to black. It is used where a paint cannot be applied
+    /// in full, e.g. for bitmap glyphs.
+    pub fn to_color(&self) -> Color {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Gradient(gradient) => gradient.sample(0.5),
+            Self::Pattern(_) => Color::BLACK,
+        }
+    }
 }
 
 impl<T: Into<Color>> From<T> for Paint {
@@ -17,6 +36,8 @@ impl Debug for Paint {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::Solid(color) => color.fmt(f),
+            Self::Gradient(gradient) => gradient.fmt(f),
+            Self::Pattern(pattern) => pattern.fmt(f),
         }
     }
 }
@@ -25,6 +46,10 @@ cast! {
     Paint,
     self => match self {
         Self::Solid(color) => Value::Color(color),
+        Self::Gradient(gradient) => Value::dynamic(gradient),
+        Self::Pattern(pattern) => Value::dynamic(pattern),
     },
     color: Color => Self::Solid(color),
+    gradient: Gradient => Self::Gradient(gradient),
+    pattern: Pattern => Self::Pattern(pattern),
 }
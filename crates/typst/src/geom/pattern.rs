@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use comemo::Prehashed;
+
+use super::*;
+use crate::doc::Frame;
+
+/// A fill that repeats a tile of content.
+///
+/// The tiles are laid out in a grid that starts at the top-left corner of the
+/// bounding box of the filled item.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Pattern(Arc<Prehashed<Repr>>);
+
+/// The internal representation of a [`Pattern`].
+#[derive(Hash)]
+struct Repr {
+    /// The tile's frame.
+    frame: Frame,
+    /// The gap between adjacent tiles.
+    spacing: Size,
+}
+
+impl Pattern {
+    /// Create a new pattern from a tile frame and the spacing between tiles.
+    ///
+    /// The tile's size is the size of the frame.
+    pub fn new(frame: Frame, spacing: Size) -> StrResult<Self> {
+        if !frame.size().is_finite() {
+            bail!("pattern tile size must be finite");
+        }
+
+        let period = frame.size() + spacing;
+        if period.x <= Abs::zero() || period.y <= Abs::zero() {
+            bail!("pattern tile size must be non-zero");
+        }
+
+        Ok(Self(Arc::new(Prehashed::new(Repr { frame, spacing }))))
+    }
+
+    /// The tile's frame.
+    pub fn frame(&self) -> &Frame {
+        &self.0.frame
+    }
+
+    /// The size of a single tile.
+    pub fn size(&self) -> Size {
+        self.0.frame.size()
+    }
+
+    /// The gap between adjacent tiles.
+    pub fn spacing(&self) -> Size {
+        self.0.spacing
+    }
+
+    /// The distance from one tile to the next, i.e. the tile's size plus the
+    /// spacing.
+    pub fn period(&self) -> Size {
+        self.size() + self.spacing()
+    }
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "pattern(size: {:?}, spacing: {:?})", self.size(), self.spacing())
+    }
+}
+
+cast! {
+    type Pattern: "pattern",
+}
@@ -51,6 +51,11 @@ impl<T: Numeric> Rel<T> {
 }
 
 impl Rel<Length> {
+    /// Convert to a relative absolute length at the given font size.
+    pub fn at(self, font_size: Abs) -> Rel<Abs> {
+        self.map(|length| length.at(font_size))
+    }
+
     /// Try to divide two relative lengths.
     pub fn try_div(self, other: Self) -> Option<f64> {
         if self.rel.is_zero() && other.rel.is_zero() {
@@ -34,6 +34,26 @@ impl<T> Sides<T> {
         }
     }
 
+    /// Create an instance with `x` for the left and right side and `y` for the
+    /// top and bottom side.
+    pub fn symmetric(x: T, y: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            left: x.clone(),
+            top: y.clone(),
+            right: x,
+            bottom: y,
+        }
+    }
+
+    /// Replace the component for one side.
+    pub fn with(mut self, side: Side, value: T) -> Self {
+        self.set(side, value);
+        self
+    }
+
     /// Map the individual fields with `f`.
     pub fn map<F, U>(self, mut f: F) -> Sides<U>
     where
@@ -191,7 +191,7 @@ impl Resolve for PartialStroke {
     }
 }
 
-impl Fold for PartialStroke<Abs> {
+impl<T> Fold for PartialStroke<T> {
     type Output = Self;
 
     fn fold(self, outer: Self::Output) -> Self::Output {
@@ -216,6 +216,14 @@ cast! {
         paint: Smart::Custom(color.into()),
         ..Default::default()
     },
+    gradient: Gradient => Self {
+        paint: Smart::Custom(Paint::Gradient(gradient)),
+        ..Default::default()
+    },
+    pattern: Pattern => Self {
+        paint: Smart::Custom(Paint::Pattern(pattern)),
+        ..Default::default()
+    },
     mut dict: Dict => {
         fn take<T: FromValue>(dict: &mut Dict, key: &str) -> StrResult<Smart<T>> {
             Ok(dict.take(key).ok().map(T::from_value)
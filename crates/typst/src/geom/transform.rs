@@ -52,6 +52,29 @@ impl Transform {
         self == Self::identity()
     }
 
+    /// The inverse of this transformation, if it is invertible.
+    pub fn invert(self) -> Option<Self> {
+        let (sx, ky, kx, sy) =
+            (self.sx.get(), self.ky.get(), self.kx.get(), self.sy.get());
+        let det = sx * sy - kx * ky;
+        if det.abs() < 1e-12 {
+            return None;
+        }
+
+        let inv_sx = sy / det;
+        let inv_ky = -ky / det;
+        let inv_kx = -kx / det;
+        let inv_sy = sx / det;
+        Some(Self {
+            sx: Ratio::new(inv_sx),
+            ky: Ratio::new(inv_ky),
+            kx: Ratio::new(inv_kx),
+            sy: Ratio::new(inv_sy),
+            tx: -(self.tx * inv_sx + self.ty * inv_kx),
+            ty: -(self.tx * inv_ky + self.ty * inv_sy),
+        })
+    }
+
     /// Pre-concatenate another transformation.
     pub fn pre_concat(self, prev: Self) -> Self {
         Transform {
@@ -75,3 +98,19 @@ impl Default for Transform {
         Self::identity()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_invert() {
+        let ts = Transform::translate(Abs::pt(5.0), Abs::pt(-3.0))
+            .pre_concat(Transform::rotate(Angle::deg(30.0)))
+            .pre_concat(Transform::scale(Ratio::new(2.0), Ratio::new(0.5)));
+        let p = Point::new(Abs::pt(7.0), Abs::pt(11.0));
+        let q = p.transform(ts).transform(ts.invert().unwrap());
+        assert!(q.x.approx_eq(p.x) && q.y.approx_eq(p.y));
+        assert_eq!(Transform::scale(Ratio::zero(), Ratio::one()).invert(), None);
+    }
+}
@@ -134,6 +134,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::HeadingMarker => None,
         SyntaxKind::ListItem => None,
         SyntaxKind::ListMarker => Some(Tag::ListMarker),
+        SyntaxKind::Checkbox => Some(Tag::ListMarker),
         SyntaxKind::EnumItem => None,
         SyntaxKind::EnumMarker => Some(Tag::ListMarker),
         SyntaxKind::TermItem => None,
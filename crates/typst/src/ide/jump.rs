@@ -57,10 +57,9 @@ pub fn jump_from_click(
     for (mut pos, item) in frame.items().rev() {
         match item {
             FrameItem::Group(group) => {
-                // TODO: Handle transformation.
-                if let Some(span) =
-                    jump_from_click(world, frames, &group.frame, click - pos)
-                {
+                let Some(ts) = group.transform.invert() else { continue };
+                let local = (click - pos).transform(ts);
+                if let Some(span) = jump_from_click(world, frames, &group.frame, local) {
                     return Some(span);
                 }
             }
@@ -143,9 +142,8 @@ pub fn jump_from_cursor(
 fn find_in_frame(frame: &Frame, span: Span) -> Option<Point> {
     for (mut pos, item) in frame.items() {
         if let FrameItem::Group(group) = item {
-            // TODO: Handle transformation.
             if let Some(point) = find_in_frame(&group.frame, span) {
-                return Some(point + pos);
+                return Some(point.transform(group.transform) + pos);
             }
         }
 
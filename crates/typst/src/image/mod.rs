@@ -1,9 +1,14 @@
 //! Image handling.
 
+mod pdf;
+
+pub use self::pdf::{PdfDict, PdfObject, PdfPage};
+
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::fmt::{self, Debug, Formatter};
 use std::io;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use comemo::{Prehashed, Track, Tracked};
@@ -20,6 +25,7 @@ use crate::diag::{bail, format_xml_like_error, StrResult};
 use crate::eval::Bytes;
 use crate::font::Font;
 use crate::geom::Axes;
+use crate::util::NonZeroExt;
 use crate::World;
 
 /// A raster or vector image.
@@ -35,6 +41,8 @@ struct Repr {
     data: Bytes,
     /// The format of the encoded `buffer`.
     format: ImageFormat,
+    /// The page to show for multi-page formats.
+    page: NonZeroUsize,
     /// The size of the image.
     size: Axes<u32>,
     /// A loader for fonts referenced by an image (currently, only applies to
@@ -58,17 +66,39 @@ impl Image {
             ImageFormat::Vector(VectorFormat::Svg) => {
                 decode_svg(&data, (&loader as &dyn SvgFontLoader).track())?
             }
+            ImageFormat::Vector(VectorFormat::Pdf) => {
+                decode_pdf(&data, NonZeroUsize::ONE)?
+            }
         };
 
         Ok(Self(Arc::new(Prehashed::new(Repr {
             data,
             format,
+            page: NonZeroUsize::ONE,
             size: decoded.size(),
             loader,
             alt,
         }))))
     }
 
+    /// Create an image from a page of a PDF file.
+    #[comemo::memoize]
+    pub fn pdf(
+        data: Bytes,
+        page: NonZeroUsize,
+        alt: Option<EcoString>,
+    ) -> StrResult<Self> {
+        let decoded = decode_pdf(&data, page)?;
+        Ok(Self(Arc::new(Prehashed::new(Repr {
+            data,
+            format: ImageFormat::Vector(VectorFormat::Pdf),
+            page,
+            size: decoded.size(),
+            loader: PreparedLoader::default(),
+            alt,
+        }))))
+    }
+
     /// Create a font-dependant image from a buffer and a format.
     #[comemo::memoize]
     pub fn with_fonts(
@@ -84,11 +114,15 @@ impl Image {
             ImageFormat::Vector(VectorFormat::Svg) => {
                 decode_svg(&data, (&loader as &dyn SvgFontLoader).track())?
             }
+            ImageFormat::Vector(VectorFormat::Pdf) => {
+                decode_pdf(&data, NonZeroUsize::ONE)?
+            }
         };
 
         Ok(Self(Arc::new(Prehashed::new(Repr {
             data,
             format,
+            page: NonZeroUsize::ONE,
             size: decoded.size(),
             loader: loader.into_prepared(),
             alt,
@@ -105,6 +139,11 @@ impl Image {
         self.0.format
     }
 
+    /// The page shown for multi-page formats.
+    pub fn page(&self) -> NonZeroUsize {
+        self.0.page
+    }
+
     /// The size of the image in pixels.
     pub fn size(&self) -> Axes<u32> {
         self.0.size
@@ -132,6 +171,9 @@ impl Image {
             ImageFormat::Vector(VectorFormat::Svg) => {
                 decode_svg(self.data(), (&self.0.loader as &dyn SvgFontLoader).track())
             }
+            ImageFormat::Vector(VectorFormat::Pdf) => {
+                decode_pdf(self.data(), self.page())
+            }
         }
         .unwrap()
     }
@@ -183,6 +225,8 @@ pub enum RasterFormat {
 pub enum VectorFormat {
     /// The vector graphics format of the web.
     Svg,
+    /// The format of print-ready documents. A single page is embedded.
+    Pdf,
 }
 
 impl RasterFormat {
@@ -235,6 +279,8 @@ pub enum DecodedImage {
     Raster(image::DynamicImage, Option<IccProfile>, RasterFormat),
     /// An decoded SVG tree.
     Svg(usvg::Tree),
+    /// A page extracted from a PDF file.
+    Pdf(PdfPage),
 }
 
 impl DecodedImage {
@@ -248,6 +294,7 @@ impl DecodedImage {
         match self {
             Self::Raster(dynamic, _, _) => dynamic.width(),
             Self::Svg(tree) => tree.size.width().ceil() as u32,
+            Self::Pdf(page) => page.width().ceil() as u32,
         }
     }
 
@@ -256,6 +303,7 @@ impl DecodedImage {
         match self {
             Self::Raster(dynamic, _, _) => dynamic.height(),
             Self::Svg(tree) => tree.size.height().ceil() as u32,
+            Self::Pdf(page) => page.height().ceil() as u32,
         }
     }
 }
@@ -307,6 +355,12 @@ fn decode_svg(
     Ok(Arc::new(DecodedImage::Svg(tree)))
 }
 
+/// Decode a page of a PDF file.
+#[comemo::memoize]
+fn decode_pdf(data: &Bytes, page: NonZeroUsize) -> StrResult<Arc<DecodedImage>> {
+    Ok(Arc::new(DecodedImage::Pdf(pdf::extract_page(data, page)?)))
+}
+
 /// Discover and load the fonts referenced by an SVG.
 fn load_svg_fonts(
     tree: &usvg::Tree,
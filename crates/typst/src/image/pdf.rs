@@ -0,0 +1,816 @@
+//! Reading pages from existing PDF files.
+//!
+//! This is not a complete PDF parser. It reads files with classic
+//! cross-reference tables, which covers most simple documents. Files that
+//! store their objects in compressed object streams are not supported.
+
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+
+use ecow::eco_format;
+
+use crate::diag::{bail, StrResult};
+
+/// How deeply objects may be nested, directly or through references.
+const MAX_DEPTH: usize = 256;
+
+/// A page extracted from a PDF file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPage {
+    /// The page's media box as lower-left and upper-right corner in points.
+    pub media_box: [f32; 4],
+    /// The page's decoded content stream.
+    pub content: Vec<u8>,
+    /// The page's resource dictionary.
+    pub resources: PdfObject,
+    /// All indirect objects reachable from the resources, by object number.
+    pub objects: BTreeMap<u32, PdfObject>,
+}
+
+impl PdfPage {
+    /// The width of the page in points.
+    pub fn width(&self) -> f32 {
+        self.media_box[2] - self.media_box[0]
+    }
+
+    /// The height of the page in points.
+    pub fn height(&self) -> f32 {
+        self.media_box[3] - self.media_box[1]
+    }
+}
+
+/// An object in a PDF file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfObject {
+    /// The null object.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer.
+    Int(i64),
+    /// A real number.
+    Real(f32),
+    /// A byte string.
+    Str(Vec<u8>),
+    /// A name, without the leading slash.
+    Name(Vec<u8>),
+    /// An array of objects.
+    Array(Vec<PdfObject>),
+    /// A dictionary.
+    Dict(PdfDict),
+    /// A dictionary with attached raw, possibly compressed, data.
+    Stream(PdfDict, Vec<u8>),
+    /// A reference to an indirect object by its number.
+    Ref(u32),
+}
+
+/// The entries of a PDF dictionary in the order they appear in the file.
+pub type PdfDict = Vec<(Vec<u8>, PdfObject)>;
+
+impl PdfObject {
+    /// Look up a key in a dictionary or stream dictionary.
+    pub fn get(&self, key: &[u8]) -> Option<&PdfObject> {
+        match self {
+            Self::Dict(dict) | Self::Stream(dict, _) => {
+                dict.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+            _ => None,
+        }
+    }
+
+    /// The object as an integer.
+    fn as_int(&self) -> Option<i64> {
+        match *self {
+            Self::Int(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The object as a number.
+    fn as_number(&self) -> Option<f32> {
+        match *self {
+            Self::Int(v) => Some(v as f32),
+            Self::Real(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Extract a page from a PDF file.
+pub fn extract_page(data: &[u8], number: NonZeroUsize) -> StrResult<PdfPage> {
+    let reader = Reader::new(data)?;
+    let root = reader.resolve(reader.trailer.get(b"Root"))?;
+    let pages = reader.resolve(root.get(b"Pages"))?;
+
+    let mut index = number.get() - 1;
+    let Some((page, inherited)) =
+        reader.find_page(&pages, &mut index, &Inherited::default(), 0)?
+    else {
+        bail!("pdf file has no page {number}");
+    };
+
+    let media_box = match inherited.media_box {
+        Some(media_box) => reader.rect(&media_box)?,
+        // US Letter is the default.
+        None => [0.0, 0.0, 612.0, 792.0],
+    };
+
+    let contents = match page.get(b"Contents") {
+        Some(contents) => match reader.resolve(Some(contents))? {
+            PdfObject::Array(items) => items,
+            stream => vec![stream],
+        },
+        None => vec![],
+    };
+
+    let mut content = vec![];
+    for item in contents {
+        let PdfObject::Stream(dict, data) = reader.resolve(Some(&item))? else {
+            bail!("pdf page content is malformed");
+        };
+        content.extend(decode_stream(&dict, &data)?);
+        content.push(b'\n');
+    }
+
+    let resources = match inherited.resources {
+        Some(resources) => reader.resolve(Some(&resources))?,
+        None => PdfObject::Dict(vec![]),
+    };
+
+    let mut objects = BTreeMap::new();
+    reader.collect(&resources, &mut objects, 0)?;
+
+    Ok(PdfPage { media_box, content, resources, objects })
+}
+
+/// Decode the data of a content stream.
+fn decode_stream(dict: &PdfDict, data: &[u8]) -> StrResult<Vec<u8>> {
+    let filter = dict.iter().find(|(k, _)| k == b"Filter").map(|(_, v)| v);
+    let params = dict.iter().any(|(k, _)| k == b"DecodeParms");
+    match filter {
+        None => Ok(data.to_vec()),
+        Some(PdfObject::Array(filters)) if filters.is_empty() => Ok(data.to_vec()),
+        Some(PdfObject::Name(name)) if name == b"FlateDecode" && !params => inflate(data),
+        Some(PdfObject::Array(filters))
+            if filters.len() == 1
+                && filters[0] == PdfObject::Name(b"FlateDecode".to_vec())
+                && !params =>
+        {
+            inflate(data)
+        }
+        _ => bail!("pdf page content uses an unsupported filter"),
+    }
+}
+
+/// Decompress zlib-compressed data.
+fn inflate(data: &[u8]) -> StrResult<Vec<u8>> {
+    miniz_oxide::inflate::decompress_to_vec_zlib(data)
+        .map_err(|_| "failed to decompress pdf page content".into())
+}
+
+/// Attributes that a page inherits from its ancestors in the page tree.
+#[derive(Debug, Default, Clone)]
+struct Inherited {
+    resources: Option<PdfObject>,
+    media_box: Option<PdfObject>,
+}
+
+/// Provides access to the objects of a PDF file.
+struct Reader<'a> {
+    data: &'a [u8],
+    offsets: HashMap<u32, usize>,
+    trailer: PdfObject,
+}
+
+impl<'a> Reader<'a> {
+    /// Read the cross-reference tables of a file.
+    fn new(data: &'a [u8]) -> StrResult<Self> {
+        if !data.starts_with(b"%PDF-") {
+            bail!("file is not a pdf");
+        }
+
+        let Some(start) = data.windows(9).rposition(|w| w == b"startxref") else {
+            bail!("pdf file has no cross-reference table");
+        };
+
+        let mut parser = Parser::new(data, start + 9);
+        let mut next = Some(parser.int()?);
+        let mut offsets = HashMap::new();
+        let mut trailer = None;
+        let mut visited = vec![];
+
+        // Follow the chain of incremental updates. Newer sections come first,
+        // so their entries take precedence.
+        while let Some(offset) = next.and_then(|v| usize::try_from(v).ok()) {
+            if visited.contains(&offset) {
+                break;
+            }
+
+            visited.push(offset);
+            if offset >= data.len() {
+                bail!("pdf file is malformed");
+            }
+
+            let mut parser = Parser::new(data, offset);
+            if !parser.eat_keyword(b"xref") {
+                bail!("pdf files with cross-reference streams are not supported");
+            }
+
+            while !parser.eat_keyword(b"trailer") {
+                let first = parser.int()?;
+                let count = parser.int()?;
+                for i in 0..count {
+                    let offset = parser.int()?;
+                    let _generation = parser.int()?;
+                    parser.skip_whitespace();
+                    let kind = parser.eat();
+                    if kind == Some(b'n') {
+                        let id =
+                            first.checked_add(i).and_then(|id| u32::try_from(id).ok());
+                        let (Some(id), Ok(offset)) = (id, usize::try_from(offset)) else {
+                            bail!("pdf file is malformed");
+                        };
+                        offsets.entry(id).or_insert(offset);
+                    }
+                }
+            }
+
+            let dict = parser.object()?;
+            if dict.get(b"Encrypt").is_some() {
+                bail!("encrypted pdf files are not supported");
+            }
+
+            next = dict.get(b"Prev").and_then(PdfObject::as_int);
+            trailer.get_or_insert(dict);
+        }
+
+        let Some(trailer) = trailer else {
+            bail!("pdf file has no trailer");
+        };
+
+        Ok(Self { data, offsets, trailer })
+    }
+
+    /// Read the indirect object with the given number.
+    fn object(&self, id: u32) -> StrResult<PdfObject> {
+        let Some((mut parser, object)) = self.header(id)? else {
+            // References to missing objects are treated as null.
+            return Ok(PdfObject::Null);
+        };
+
+        let dict = match object {
+            PdfObject::Dict(dict) => dict,
+            object => return Ok(object),
+        };
+
+        if !parser.eat_keyword(b"stream") {
+            return Ok(PdfObject::Dict(dict));
+        }
+
+        // The stream data starts after the end of the line.
+        if parser.peek() == Some(b'\r') {
+            parser.eat();
+        }
+        if parser.peek() == Some(b'\n') {
+            parser.eat();
+        }
+
+        let length = dict.iter().find(|(k, _)| k == b"Length").map(|(_, v)| v);
+        let length = match length {
+            Some(&PdfObject::Ref(id)) => {
+                self.header(id)?.and_then(|(_, length)| length.as_int())
+            }
+            Some(length) => length.as_int(),
+            None => None,
+        };
+
+        let start = parser.cursor;
+        let Some(data) = length
+            .and_then(|length| usize::try_from(length).ok())
+            .and_then(|length| self.data.get(start..start.checked_add(length)?))
+        else {
+            bail!("pdf stream {id} is malformed");
+        };
+
+        Ok(PdfObject::Stream(dict, data.to_vec()))
+    }
+
+    /// Parse the beginning of an indirect object, without any stream data.
+    fn header(&self, id: u32) -> StrResult<Option<(Parser<'a>, PdfObject)>> {
+        let Some(&offset) = self.offsets.get(&id) else { return Ok(None) };
+        if offset >= self.data.len() {
+            bail!("pdf object {id} is malformed");
+        }
+
+        let mut parser = Parser::new(self.data, offset);
+        parser.int()?;
+        parser.int()?;
+        if !parser.eat_keyword(b"obj") {
+            bail!("pdf object {id} is malformed");
+        }
+
+        let object = parser.object()?;
+        Ok(Some((parser, object)))
+    }
+
+    /// Resolve an object if it is a reference.
+    fn resolve(&self, object: Option<&PdfObject>) -> StrResult<PdfObject> {
+        match object {
+            Some(&PdfObject::Ref(id)) => self.object(id),
+            Some(object) => Ok(object.clone()),
+            None => Ok(PdfObject::Null),
+        }
+    }
+
+    /// Read a rectangle, normalizing it so that the first corner is the lower
+    /// left one.
+    fn rect(&self, object: &PdfObject) -> StrResult<[f32; 4]> {
+        let PdfObject::Array(items) = self.resolve(Some(object))? else {
+            bail!("pdf page has a malformed media box");
+        };
+
+        let mut rect = [0.0; 4];
+        for (slot, item) in rect.iter_mut().zip(&items) {
+            let Some(v) = self.resolve(Some(item))?.as_number() else {
+                bail!("pdf page has a malformed media box");
+            };
+            *slot = v;
+        }
+
+        let [x0, y0, x1, y1] = rect;
+        if items.len() != 4 || x0 == x1 || y0 == y1 {
+            bail!("pdf page has a malformed media box");
+        }
+
+        Ok([x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)])
+    }
+
+    /// Find the page with the given index in a page tree node, decrementing
+    /// the index by the number of skipped pages.
+    fn find_page(
+        &self,
+        node: &PdfObject,
+        index: &mut usize,
+        inherited: &Inherited,
+        depth: usize,
+    ) -> StrResult<Option<(PdfObject, Inherited)>> {
+        if depth > 64 {
+            bail!("pdf page tree is too deep");
+        } else if !matches!(node, PdfObject::Dict(_)) {
+            bail!("pdf page tree is malformed");
+        }
+
+        let mut inherited = inherited.clone();
+        if let Some(resources) = node.get(b"Resources") {
+            inherited.resources = Some(resources.clone());
+        }
+        if let Some(media_box) = node.get(b"MediaBox") {
+            inherited.media_box = Some(media_box.clone());
+        }
+
+        let is_page = node.get(b"Type") == Some(&PdfObject::Name(b"Page".to_vec()));
+        let kids = match node.get(b"Kids") {
+            Some(kids) if !is_page => kids,
+            _ => {
+                if *index == 0 {
+                    return Ok(Some((node.clone(), inherited)));
+                }
+                *index -= 1;
+                return Ok(None);
+            }
+        };
+
+        // Skip whole subtrees without reading them.
+        let count = node.get(b"Count").and_then(PdfObject::as_int);
+        if let Some(count) = count.and_then(|v| usize::try_from(v).ok()) {
+            if *index >= count {
+                *index -= count;
+                return Ok(None);
+            }
+        }
+
+        let PdfObject::Array(kids) = self.resolve(Some(kids))? else {
+            bail!("pdf page tree is malformed");
+        };
+
+        for kid in &kids {
+            let kid = self.resolve(Some(kid))?;
+            if let Some(found) = self.find_page(&kid, index, &inherited, depth + 1)? {
+                return Ok(Some(found));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Collect all indirect objects reachable from an object.
+    fn collect(
+        &self,
+        object: &PdfObject,
+        objects: &mut BTreeMap<u32, PdfObject>,
+        depth: usize,
+    ) -> StrResult<()> {
+        if depth > MAX_DEPTH {
+            bail!("pdf file is nested too deeply");
+        }
+
+        match object {
+            &PdfObject::Ref(id) => {
+                if !objects.contains_key(&id) {
+                    // Insert a placeholder first so that cycles terminate.
+                    objects.insert(id, PdfObject::Null);
+                    let resolved = self.object(id)?;
+                    self.collect(&resolved, objects, depth + 1)?;
+                    objects.insert(id, resolved);
+                }
+            }
+            PdfObject::Array(items) => {
+                for item in items {
+                    self.collect(item, objects, depth + 1)?;
+                }
+            }
+            PdfObject::Dict(dict) => {
+                for (_, value) in dict {
+                    self.collect(value, objects, depth + 1)?;
+                }
+            }
+            PdfObject::Stream(dict, _) => {
+                // The length is rewritten when the stream is embedded.
+                for (_, value) in dict.iter().filter(|(k, _)| k != b"Length") {
+                    self.collect(value, objects, depth + 1)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Parses PDF objects from raw bytes.
+struct Parser<'a> {
+    data: &'a [u8],
+    cursor: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a [u8], cursor: usize) -> Self {
+        Self { data, cursor, depth: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.cursor).copied()
+    }
+
+    fn eat(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.cursor += 1;
+        Some(c)
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        self.data.get(self.cursor..).unwrap_or_default()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if is_whitespace(c) {
+                self.cursor += 1;
+            } else if c == b'%' {
+                while !matches!(self.peek(), None | Some(b'\r' | b'\n')) {
+                    self.cursor += 1;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Consume a keyword if it is next.
+    fn eat_keyword(&mut self, keyword: &[u8]) -> bool {
+        self.skip_whitespace();
+        let rest = self.rest();
+        if rest.starts_with(keyword)
+            && rest.get(keyword.len()).map_or(true, |&c| !is_regular(c))
+        {
+            self.cursor += keyword.len();
+            return true;
+        }
+        false
+    }
+
+    /// Parse an integer.
+    fn int(&mut self) -> StrResult<i64> {
+        match self.number()? {
+            PdfObject::Int(v) => Ok(v),
+            _ => bail!("pdf file is malformed"),
+        }
+    }
+
+    /// Parse an integer or real number.
+    fn number(&mut self) -> StrResult<PdfObject> {
+        self.skip_whitespace();
+        let start = self.cursor;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.cursor += 1;
+        }
+        while matches!(self.peek(), Some(b'0'..=b'9' | b'.')) {
+            self.cursor += 1;
+        }
+
+        let text = self
+            .data
+            .get(start..self.cursor)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .ok_or("pdf file is malformed")?;
+        if text.contains('.') {
+            if let Ok(v) = text.parse() {
+                return Ok(PdfObject::Real(v));
+            }
+        } else if let Ok(v) = text.parse() {
+            return Ok(PdfObject::Int(v));
+        }
+
+        bail!("pdf file is malformed")
+    }
+
+    /// Parse any direct object or reference.
+    fn object(&mut self) -> StrResult<PdfObject> {
+        if self.depth > MAX_DEPTH {
+            bail!("pdf file is nested too deeply");
+        }
+
+        self.skip_whitespace();
+        self.depth += 1;
+        let result = match self.peek() {
+            Some(b'/') => {
+                self.cursor += 1;
+                Ok(PdfObject::Name(self.name()))
+            }
+            Some(b'<') if self.rest().starts_with(b"<<") => self.dict(),
+            Some(b'<') => self.hex_string(),
+            Some(b'(') => self.literal_string(),
+            Some(b'[') => self.array(),
+            Some(b'0'..=b'9' | b'+' | b'-' | b'.') => self.number_or_ref(),
+            _ => {
+                if self.eat_keyword(b"true") {
+                    Ok(PdfObject::Bool(true))
+                } else if self.eat_keyword(b"false") {
+                    Ok(PdfObject::Bool(false))
+                } else if self.eat_keyword(b"null") {
+                    Ok(PdfObject::Null)
+                } else {
+                    Err(eco_format!("pdf file is malformed"))
+                }
+            }
+        };
+        self.depth -= 1;
+        result
+    }
+
+    /// Parse a number or, if followed by a generation and `R`, a reference.
+    fn number_or_ref(&mut self) -> StrResult<PdfObject> {
+        let number = self.number()?;
+        if let PdfObject::Int(id) = number {
+            let checkpoint = self.cursor;
+            if let (Ok(PdfObject::Int(_)), true) = (self.number(), self.eat_keyword(b"R"))
+            {
+                if let Ok(id) = u32::try_from(id) {
+                    return Ok(PdfObject::Ref(id));
+                }
+            }
+            self.cursor = checkpoint;
+        }
+        Ok(number)
+    }
+
+    /// Parse a name after its slash.
+    fn name(&mut self) -> Vec<u8> {
+        let mut name = vec![];
+        while let Some(c) = self.peek().filter(|&c| is_regular(c)) {
+            self.cursor += 1;
+            if c == b'#' {
+                let hex =
+                    self.rest().get(..2).and_then(|hex| std::str::from_utf8(hex).ok());
+                if let Some(v) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    self.cursor += 2;
+                    name.push(v);
+                    continue;
+                }
+            }
+            name.push(c);
+        }
+        name
+    }
+
+    /// Parse a dictionary.
+    fn dict(&mut self) -> StrResult<PdfObject> {
+        self.cursor += 2;
+        let mut dict = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with(b">>") {
+                self.cursor += 2;
+                return Ok(PdfObject::Dict(dict));
+            }
+
+            let PdfObject::Name(key) = self.object()? else {
+                bail!("pdf file is malformed");
+            };
+            let value = self.object()?;
+            dict.push((key, value));
+        }
+    }
+
+    /// Parse an array.
+    fn array(&mut self) -> StrResult<PdfObject> {
+        self.cursor += 1;
+        let mut items = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(b']') {
+                self.cursor += 1;
+                return Ok(PdfObject::Array(items));
+            }
+            items.push(self.object()?);
+        }
+    }
+
+    /// Parse a hexadecimal string.
+    fn hex_string(&mut self) -> StrResult<PdfObject> {
+        self.cursor += 1;
+        let mut digits = vec![];
+        loop {
+            match self.eat() {
+                Some(b'>') => break,
+                Some(c) if c.is_ascii_hexdigit() => digits.push(c),
+                Some(c) if is_whitespace(c) => {}
+                _ => bail!("pdf file is malformed"),
+            }
+        }
+
+        if digits.len() % 2 == 1 {
+            digits.push(b'0');
+        }
+
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| {
+                let hex = std::str::from_utf8(pair).unwrap_or_default();
+                u8::from_str_radix(hex, 16).unwrap_or_default()
+            })
+            .collect();
+
+        Ok(PdfObject::Str(bytes))
+    }
+
+    /// Parse a literal string in parentheses.
+    fn literal_string(&mut self) -> StrResult<PdfObject> {
+        self.cursor += 1;
+        let mut bytes = vec![];
+        let mut depth = 1;
+        loop {
+            let Some(c) = self.eat() else { bail!("pdf file is malformed") };
+            match c {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(PdfObject::Str(bytes));
+                    }
+                }
+                b'\\' => {
+                    let Some(c) = self.eat() else { bail!("pdf file is malformed") };
+                    let escaped = match c {
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        b'b' => 0x08,
+                        b'f' => 0x0c,
+                        // A backslash at the end of a line continues the string.
+                        b'\r' => {
+                            if self.peek() == Some(b'\n') {
+                                self.cursor += 1;
+                            }
+                            continue;
+                        }
+                        b'\n' => continue,
+                        b'0'..=b'7' => {
+                            let mut v = u32::from(c - b'0');
+                            for _ in 0..2 {
+                                match self.peek() {
+                                    Some(d @ b'0'..=b'7') => {
+                                        self.cursor += 1;
+                                        v = v * 8 + u32::from(d - b'0');
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            v as u8
+                        }
+                        c => c,
+                    };
+                    bytes.push(escaped);
+                    continue;
+                }
+                _ => {}
+            }
+            bytes.push(c);
+        }
+    }
+}
+
+/// Whether a byte is PDF whitespace.
+fn is_whitespace(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\r' | b'\n' | b'\x0c' | b'\0')
+}
+
+/// Whether a byte is neither whitespace nor a delimiter.
+fn is_regular(c: u8) -> bool {
+    !is_whitespace(c) && !b"()<>[]{}/%".contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::NonZeroExt;
+
+    /// Assemble a PDF file from numbered objects and a trailer.
+    fn pdf(objects: &[String], trailer: &str) -> Vec<u8> {
+        let mut data = b"%PDF-1.4\n".to_vec();
+        let mut offsets = vec![];
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(data.len());
+            data.extend(format!("{} 0 obj\n{object}\nendobj\n", i + 1).bytes());
+        }
+
+        let xref = data.len();
+        data.extend(
+            format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1).bytes(),
+        );
+        for offset in offsets {
+            data.extend(format!("{offset:010} 00000 n \n").bytes());
+        }
+
+        data.extend(format!("trailer\n{trailer}\nstartxref\n{xref}\n%%EOF").bytes());
+        data
+    }
+
+    /// A file with one page and the given resources.
+    fn with_resources(resources: &str, extra: &[String]) -> Vec<u8> {
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".into(),
+            format!("<< /Type /Pages /Kids [3 0 R] /Count 1 /Resources {resources} >>"),
+            "<< /Type /Page /MediaBox [0 0 20 10] >>".into(),
+        ];
+        objects.extend_from_slice(extra);
+        pdf(&objects, "<< /Root 1 0 R >>")
+    }
+
+    #[test]
+    fn test_extract_page() {
+        let data = with_resources(
+            "<< /Font << /F1 4 0 R >> >>",
+            &["<< /Type /Font /Size 5000000000 >>".into()],
+        );
+        let page = extract_page(&data, NonZeroUsize::ONE).unwrap();
+        assert_eq!((page.width(), page.height()), (20.0, 10.0));
+        assert_eq!(page.objects.len(), 1);
+        assert_eq!(page.objects[&4].get(b"Size"), Some(&PdfObject::Int(5000000000)));
+    }
+
+    #[test]
+    fn test_extract_page_encrypted() {
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".into(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".into(),
+            "<< /Type /Page >>".into(),
+            "<< /Filter /Standard /V 1 >>".into(),
+        ];
+        let data = pdf(&objects, "<< /Root 1 0 R /Encrypt 4 0 R >>");
+        let error = extract_page(&data, NonZeroUsize::ONE).unwrap_err();
+        assert_eq!(error.as_str(), "encrypted pdf files are not supported");
+
+        objects.pop();
+        assert!(
+            extract_page(&pdf(&objects, "<< /Root 1 0 R >>"), NonZeroUsize::ONE).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_extract_page_nested_too_deeply() {
+        // Directly nested arrays.
+        let depth = 10 * MAX_DEPTH;
+        let nested = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let data = with_resources(&format!("<< /Array {nested} >>"), &[]);
+        let error = extract_page(&data, NonZeroUsize::ONE).unwrap_err();
+        assert_eq!(error.as_str(), "pdf file is nested too deeply");
+
+        // A long chain of references.
+        let chain: Vec<String> =
+            (5..depth + 5).map(|next| format!("<< /Next {next} 0 R >>")).collect();
+        let data = with_resources("<< /First 4 0 R >>", &chain);
+        let error = extract_page(&data, NonZeroUsize::ONE).unwrap_err();
+        assert_eq!(error.as_str(), "pdf file is nested too deeply");
+    }
+}
@@ -62,9 +62,16 @@ use crate::diag::{FileResult, SourceResult};
 use crate::doc::Document;
 use crate::eval::{Bytes, Datetime, Library, Route, Tracer};
 use crate::font::{Font, FontBook};
+use crate::model::Content;
 use crate::syntax::{FileId, PackageSpec, Source, Span};
 
 /// Compile a source file into a fully layouted document.
+///
+/// Parsing happens when the world creates a [`Source`], which owns its syntax
+/// tree. The tree thus remains available through [`Source::root`] and does not
+/// need to be returned here. Evaluation and layout are memoized, so to
+/// re-layout after a change, [edit](Source::edit) the source in-place and
+/// compile again: only the parts affected by the edit are recomputed.
 #[tracing::instrument(skip_all)]
 pub fn compile(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Document> {
     let route = Route::default();
@@ -85,6 +92,22 @@ pub fn compile(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Document>
     model::typeset(world, tracer, &module.content())
 }
 
+/// Lay out content that was built directly instead of evaluated from source.
+///
+/// This is useful for host programs that generate documents from data. The
+/// world's [main](World::main) source is not evaluated, but the world still
+/// provides the fonts and the files that the content refers to.
+#[tracing::instrument(skip_all)]
+pub fn compile_content(
+    world: &dyn World,
+    tracer: &mut Tracer,
+    content: &Content,
+) -> SourceResult<Document> {
+    // Hook up the lang items, which evaluation would otherwise do.
+    eval::set_lang_items(world.library().items.clone());
+    model::typeset(world.track(), tracer.track_mut(), content)
+}
+
 /// The environment in which typesetting occurs.
 ///
 /// All loading functions (`main`, `source`, `file`, `font`) should perform
@@ -12,7 +12,7 @@ use super::{
     Recipe, Selector, Style, Styles, Synthesize,
 };
 use crate::diag::{SourceResult, StrResult};
-use crate::doc::Meta;
+use crate::doc::{Meta, TextStats};
 use crate::eval::{Dict, FromValue, IntoValue, Str, Value, Vm};
 use crate::syntax::Span;
 use crate::util::pretty_array_like;
@@ -405,6 +405,11 @@ impl Content {
         text
     }
 
+    /// Count the words and characters in the plain text of this content.
+    pub fn text_stats(&self) -> TextStats {
+        TextStats::of(&self.plain_text())
+    }
+
     /// Traverse this content.
     fn traverse<'a, F>(&'a self, f: &mut F)
     where
@@ -0,0 +1,852 @@
+//! Tests for the Rust API that host programs use to compile documents.
+
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use comemo::{Prehashed, Track};
+use typst::diag::{FileError, FileResult};
+use typst::doc::{Document, Frame, FrameItem, GroupItem, TextItem};
+use typst::eval::{Bytes, Datetime, Library, Tracer};
+use typst::export::{ContactSheet, RenderOptions};
+use typst::font::{Font, FontBook};
+use typst::geom::{
+    Abs, Align, Angle, Axes, Color, GenAlign, Geometry, Paint, PathItem, Point, Shape,
+    Size, Transform,
+};
+use typst::ide::{jump_from_click, Jump};
+use typst::model::{
+    Content, DelayedErrors, Introspector, Locator, StyleChain, Styles, Vt,
+};
+use typst::syntax::{FileId, Source, Span};
+use typst::World;
+use typst_library::builder::*;
+use typst_library::compile_to_pdf;
+use typst_library::layout::{align_frame, AlignElem};
+use typst_library::text::measure_text;
+
+const FONT_DIR: &str = "../assets/fonts";
+const ASSET_DIR: &str = "../assets";
+
+/// A world with the test fonts and assets, but no other sources.
+struct FontWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    main: Source,
+}
+
+impl FontWorld {
+    fn new() -> Self {
+        let mut fonts = vec![];
+        for entry in fs::read_dir(FONT_DIR).unwrap() {
+            let data = fs::read(entry.unwrap().path()).unwrap();
+            fonts.extend(Font::iter(data.into()));
+        }
+        Self::with_fonts(fonts)
+    }
+
+    /// Create a world with just the given fonts.
+    fn with_fonts(fonts: Vec<Font>) -> Self {
+        Self {
+            library: Prehashed::new(typst_library::build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            main: Source::detached(""),
+        }
+    }
+
+    /// Compile a source string.
+    fn compile_source(mut self, src: &str) -> Document {
+        self.main = Source::detached(src);
+        typst::compile(&self, &mut Tracer::default()).unwrap()
+    }
+
+    /// Lay out built content.
+    fn compile(&self, content: &Content) -> Document {
+        typst::compile_content(self, &mut Tracer::default(), content).unwrap()
+    }
+}
+
+impl World for FontWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.main.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.main.id() {
+            Ok(self.main.clone())
+        } else {
+            Err(FileError::NotFound(id.path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        let path = id.path().strip_prefix("/").unwrap_or(id.path());
+        let path = Path::new(ASSET_DIR).join(path);
+        fs::read(&path)
+            .map(Bytes::from)
+            .map_err(|err| FileError::from_io(err, &path))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, _: Option<i64>) -> Option<Datetime> {
+        None
+    }
+}
+
+/// The path of a font file from the test fonts.
+fn font(name: &str) -> PathBuf {
+    PathBuf::from(FONT_DIR).join(name)
+}
+
+/// All text in a frame, in order.
+fn text_in(frame: &Frame) -> String {
+    let mut text = String::new();
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => text += &text_in(&group.frame),
+            FrameItem::Text(item) => text += &item.text,
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The first text item in a frame.
+fn first_text(frame: &Frame) -> Option<&TextItem> {
+    frame.items().find_map(|(_, item)| match item {
+        FrameItem::Group(group) => first_text(&group.frame),
+        FrameItem::Text(item) => Some(item),
+        _ => None,
+    })
+}
+
+/// All text items in a frame, in order.
+fn text_items(frame: &Frame) -> Vec<&TextItem> {
+    let mut items = vec![];
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => items.extend(text_items(&group.frame)),
+            FrameItem::Text(item) => items.push(item),
+            _ => {}
+        }
+    }
+    items
+}
+
+/// All text items in a frame with their positions relative to the frame, in
+/// order.
+fn placed_text(frame: &Frame) -> Vec<(Point, &TextItem)> {
+    let mut items = vec![];
+    for &(pos, ref item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => items.extend(
+                placed_text(&group.frame)
+                    .into_iter()
+                    .map(|(inner, text)| (pos + inner, text)),
+            ),
+            FrameItem::Text(text) => items.push((pos, text)),
+            _ => {}
+        }
+    }
+    items
+}
+
+/// All shapes in a frame, with their positions relative to the frame.
+fn shapes(frame: &Frame) -> Vec<(Point, &Shape)> {
+    let mut items = vec![];
+    for &(pos, ref item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => items.extend(
+                shapes(&group.frame)
+                    .into_iter()
+                    .map(|(inner, shape)| (pos + inner, shape)),
+            ),
+            FrameItem::Shape(shape, _) => items.push((pos, shape)),
+            _ => {}
+        }
+    }
+    items
+}
+
+/// Rename a table in the table directory of a font file.
+fn rename_table(data: &mut [u8], from: &[u8; 4], to: &[u8; 4]) {
+    let count = u16::from_be_bytes([data[4], data[5]]) as usize;
+    for i in 0..count {
+        let tag = 12 + 16 * i;
+        if &data[tag..tag + 4] == from {
+            data[tag..tag + 4].copy_from_slice(to);
+        }
+    }
+}
+
+/// Whether the haystack contains the needle.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    count(haystack, needle) > 0
+}
+
+/// How often the needle occurs in the haystack.
+fn count(haystack: &[u8], needle: &[u8]) -> usize {
+    haystack
+        .windows(needle.len())
+        .filter(|&window| window == needle)
+        .count()
+}
+
+#[test]
+fn test_compile_to_pdf() {
+    let pdf = compile_to_pdf("Hello", &[font("LinLibertine_R.ttf")]).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+    assert!(contains(&pdf, b"/FontFile2"));
+}
+
+#[test]
+fn test_compile_to_pdf_errors() {
+    let errors = compile_to_pdf("#foo", &[]).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "unknown variable: foo");
+
+    let errors = compile_to_pdf("Hello", &[font("missing.ttf")]).unwrap_err();
+    assert!(errors[0].message.starts_with("failed to load font file"));
+}
+
+#[test]
+fn test_table_strokes() {
+    // The lines of a frame with their position, target and stroke.
+    let lines = |src: &str| {
+        let document = FontWorld::new().compile_source(src);
+        let lines: Vec<_> = shapes(&document.pages[0])
+            .into_iter()
+            .filter_map(|(pos, shape)| match shape.geometry {
+                Geometry::Line(target) => Some((pos, target, shape.stroke.clone()?)),
+                _ => None,
+            })
+            .collect();
+        (document, lines)
+    };
+
+    // A filled header with horizontal lines only between the rows.
+    let (document, header) = lines(
+        "#table(\n\
+           columns: 3,\n\
+           fill: (col, row) => if row == 0 { luma(230) },\n\
+           stroke: (col, row) => (top: if row > 0 { 0.5pt }),\n\
+           [*Name*], [*Age*], [*City*],\n\
+           [Anna], [32], [Berlin],\n\
+           [Ben], [27], [Paris],\n\
+         )",
+    );
+    let fills: Vec<_> = shapes(&document.pages[0])
+        .into_iter()
+        .filter(|(_, shape)| shape.fill.is_some())
+        .map(|(pos, _)| pos)
+        .collect();
+    assert_eq!(fills.len(), 3);
+    assert!(fills.iter().all(|pos| pos.y == fills[0].y));
+    assert_eq!(header.len(), 6);
+    for (pos, target, stroke) in &header {
+        assert_eq!(target.y, Abs::zero());
+        assert_eq!(stroke.thickness, Abs::pt(0.5));
+        assert!(pos.y > fills[0].y);
+    }
+
+    // A uniform stroke keeps the thickness from an earlier set rule.
+    let (_, uniform) = lines("#set table(stroke: 2pt)\n#table(stroke: red, [A])");
+    assert_eq!(uniform.len(), 4);
+    for (_, _, stroke) in &uniform {
+        assert_eq!(stroke.thickness, Abs::pt(2.0));
+        assert_eq!(stroke.paint, Paint::Solid(Color::RED));
+    }
+
+    // Setting only one side keeps the other sides and the thickness.
+    let (_, sides) = lines("#set table(stroke: 2pt)\n#table(stroke: (top: blue), [B])");
+    assert_eq!(sides.len(), 4);
+    let horizontal = |target: &Point| target.y == Abs::zero();
+    let top = sides
+        .iter()
+        .filter(|(_, target, _)| horizontal(target))
+        .map(|(pos, _, _)| pos.y)
+        .fold(Abs::inf(), Abs::min);
+    for (pos, target, stroke) in &sides {
+        let paint =
+            if horizontal(target) && pos.y == top { Color::BLUE } else { Color::BLACK };
+        assert_eq!(stroke.thickness, Abs::pt(2.0));
+        assert_eq!(stroke.paint, Paint::Solid(paint));
+    }
+}
+
+#[test]
+fn test_pdf_document_info() {
+    let export = |src: &str| {
+        let document = FontWorld::new().compile_source(src);
+        typst::export::pdf(&document, &mut Tracer::default())
+    };
+
+    let pdf = export(
+        "#set document(\n\
+           subject: \"Testing\",\n\
+           keywords: (\"typst\", \"pdf\"),\n\
+           date: datetime(year: 2023, month: 9, day: 4),\n\
+         )\n\
+         Hello",
+    );
+    assert!(contains(&pdf, b"/Subject (Testing)"));
+    assert!(contains(&pdf, b"/Keywords (typst, pdf)"));
+    assert!(contains(&pdf, b"/CreationDate (D:20230904"));
+
+    // Fields that aren't set are left out instead of being written empty.
+    let pdf = export("#set document(keywords: \"typst\")\nHello");
+    assert!(contains(&pdf, b"/Keywords (typst)"));
+    for key in [&b"/Title"[..], b"/Author", b"/Subject", b"/CreationDate"] {
+        assert!(!contains(&pdf, key));
+    }
+}
+
+#[test]
+fn test_pdf_subsetting_failure() {
+    // Without its `loca` table, the font can still be used for layout, but
+    // not be subsetted.
+    let mut data = fs::read(font("LinLibertine_R.ttf")).unwrap();
+    rename_table(&mut data, b"loca", b"locb");
+    let broken = Font::new(data.into(), 0).unwrap();
+    let document = FontWorld::with_fonts(vec![broken]).compile_source("Hello");
+
+    let mut tracer = Tracer::default();
+    let pdf = typst::export::pdf(&document, &mut tracer);
+    let warnings = tracer.warnings();
+    assert_eq!(warnings.len(), 1);
+    let message = &warnings[0].message;
+    assert!(message.starts_with("failed to subset font Linux Libertine"));
+    assert!(message.ends_with("embedding it in full"));
+    assert!(contains(&pdf, b"/FontFile2"));
+}
+
+#[test]
+fn test_opacity_group_overlap() {
+    // The red square covers the right half of the blue one. Within a group,
+    // the overlap is drawn opaquely first, so it looks like the red square
+    // alone and isn't darkened by blending twice.
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 60pt, height: 40pt, margin: 0pt)\n\
+         #group(opacity: 50%, stack(\n\
+           dir: ltr,\n\
+           spacing: -20pt,\n\
+           square(size: 40pt, fill: blue),\n\
+           square(size: 40pt, fill: red),\n\
+         ))",
+    );
+
+    let pixmap = typst::export::render(&document.pages[0], 1.0, Color::WHITE);
+    let pixel = |x| pixmap.pixel(x, 20).unwrap();
+    assert_eq!(pixel(30), pixel(50));
+    assert_ne!(pixel(10), pixel(30));
+    assert_ne!(pixel(50).green(), 0);
+}
+
+#[test]
+fn test_opacity_group_pdf() {
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 40pt, height: 40pt, margin: 0pt)\n\
+         #group(opacity: 50%, stack(\n\
+           rect(width: 40pt, height: 30pt, fill: gradient.linear(red, blue)),\n\
+           link(\"https://typst.app\", block(width: 20pt, height: 10pt)),\n\
+         ))",
+    );
+
+    let pdf = typst::export::pdf(&document, &mut Tracer::default());
+    assert!(contains(&pdf, b"/Subtype /Form"));
+
+    // The gradient is placed in the group's coordinate system, which the
+    // group's content stream already draws in.
+    assert!(contains(&pdf, b"/Matrix [1 0 0 1 0 0]"));
+    assert!(!contains(&pdf, b"/Matrix [1 0 0 -1"));
+
+    // The link is still placed in page coordinates.
+    assert!(contains(&pdf, b"/Rect [0 10 20 0]"));
+}
+
+#[test]
+fn test_gradient_text() {
+    let document = FontWorld::new().compile_source(
+        "#set page(width: auto, height: auto, margin: 0pt)\n\
+         #text(size: 32pt, fill: gradient.linear(red, blue))[HHHH]",
+    );
+
+    // The gradient spans the whole run instead of restarting for each glyph,
+    // so the text goes from red on the left to blue on the right.
+    let pixmap = typst::export::render(&document.pages[0], 2.0, Color::WHITE);
+    let width = pixmap.width();
+    let mut left = 0;
+    let mut right = 0;
+    for (i, p) in pixmap.pixels().iter().enumerate() {
+        if p.red() == 255 && p.green() == 255 && p.blue() == 255 {
+            continue;
+        }
+        let x = i as u32 % width;
+        if x < width / 4 {
+            assert!(p.red() > p.blue());
+            left += 1;
+        } else if x >= width * 3 / 4 {
+            assert!(p.blue() > p.red());
+            right += 1;
+        }
+    }
+    assert!(left > 0 && right > 0);
+
+    // In the PDF, the text is filled with an axial shading pattern.
+    let pdf = typst::export::pdf(&document, &mut Tracer::default());
+    assert!(contains(&pdf, b"/PatternType 2"));
+    assert!(contains(&pdf, b"/ShadingType 2"));
+}
+
+#[test]
+fn test_pdf_image_export() {
+    let document = FontWorld::new().compile_source(
+        "#set page(width: auto, height: auto, margin: 0pt)\n\
+         #image(\"/files/cover.pdf\")",
+    );
+
+    // The page is embedded as a form XObject with the page's media box,
+    // along with the resources it uses.
+    let pdf = typst::export::pdf(&document, &mut Tracer::default());
+    assert!(contains(&pdf, b"/Subtype /Form"));
+    assert!(contains(&pdf, b"/BBox [0 0 200 100]"));
+    assert!(contains(&pdf, b"/Type /Font"));
+    assert!(contains(&pdf, b"/BaseFont /Helvetica"));
+}
+
+#[test]
+fn test_jump_from_click_in_rotated_group() {
+    let mut world = FontWorld::new();
+    world.main = Source::detached("#rect()");
+    let span = world.main.root().span();
+
+    // A 20pt x 10pt rectangle, rotated by 90 degrees. It ends up covering
+    // x in -10..0 and y in 0..20 relative to the group's position.
+    let mut inner = Frame::new(Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+    let rect = Geometry::Rect(inner.size()).filled(Color::BLACK.into());
+    inner.push(Point::zero(), FrameItem::Shape(rect, span));
+    let mut group = GroupItem::new(inner);
+    group.transform = Transform::rotate(Angle::deg(90.0));
+
+    let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+    let pos = Point::splat(Abs::pt(50.0));
+    frame.push(pos, FrameItem::Group(group));
+
+    let jump = |x, y| {
+        let click = Point::new(Abs::pt(x), Abs::pt(y));
+        jump_from_click(&world, &[], &frame, click)
+    };
+
+    let target = Some(Jump::Source(span.id(), 0));
+    assert_eq!(jump(45.0, 60.0), target);
+    assert_eq!(jump(55.0, 55.0), None);
+}
+
+#[test]
+fn test_pdf_range() {
+    // Ten pages, the second of which links to the third and the ninth.
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 100pt, height: 100pt)\n\
+         #for i in range(10) {\n\
+           if i > 0 { pagebreak() }\n\
+           [Page #(i + 1)]\n\
+           if i == 1 {\n\
+             link((page: 3, x: 10pt, y: 50pt))[In]\n\
+             link((page: 9, x: 10pt, y: 50pt))[Out]\n\
+           }\n\
+         }",
+    );
+    assert_eq!(document.pages.len(), 10);
+
+    let export =
+        |range| typst::export::pdf_range(&document, range, &mut Tracer::default());
+
+    // Pages two to four. The page tree and resources take the first two
+    // object ids, so the pages get the ids three to five. Both links are
+    // written, but only the one to an exported page has a destination.
+    let pdf = export(1..4);
+    assert!(pdf.starts_with(b"%PDF"));
+    assert!(contains(&pdf, b"/Count 3"));
+    assert_eq!(count(&pdf, b"/Subtype /Link"), 2);
+    assert_eq!(count(&pdf, b"/XYZ"), 1);
+    assert!(contains(&pdf, b"[4 0 R /XYZ 10 60 null]"));
+
+    // Out-of-bounds ranges are clamped to the document's pages.
+    assert!(contains(&export(8..20), b"/Count 2"));
+    assert!(contains(&export(12..15), b"/Count 0"));
+}
+
+#[test]
+fn test_progress() {
+    let reported = Arc::new(Mutex::new(vec![]));
+    let sink = reported.clone();
+    let mut tracer = Tracer::default()
+        .with_progress(move |index, size| sink.lock().unwrap().push((index, size)));
+
+    let mut world = FontWorld::new();
+    world.main = Source::detached(
+        "#set page(width: 120pt, height: 80pt)\n\
+         #lorem(150)\n\
+         #page(width: 60pt)[Small]",
+    );
+
+    // Each page is reported once, in order, with its final size.
+    let document = typst::compile(&world, &mut tracer).unwrap();
+    let expected: Vec<_> =
+        document.pages.iter().map(|frame| frame.size()).enumerate().collect();
+    assert!(expected.len() > 3);
+    assert_eq!(*reported.lock().unwrap(), expected);
+}
+
+#[test]
+fn test_render_options() {
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 80pt, height: 40pt, margin: 5pt)\n\
+         #circle(radius: 8pt, fill: black)\n\
+         Hello",
+    );
+
+    let render = |opts: &RenderOptions| {
+        typst::export::render_with(&document.pages[0], 2.0, Color::WHITE, opts)
+    };
+
+    // How many pixels are neither fully white nor fully black and how much
+    // ink there is in total.
+    let coverage = |opts: &RenderOptions| {
+        let pixmap = render(opts);
+        let ink: Vec<u8> = pixmap.pixels().iter().map(|p| 255 - p.red()).collect();
+        let partial = ink.iter().filter(|&&v| v != 0 && v != 255).count();
+        let total: u32 = ink.iter().map(|&v| u32::from(v)).sum();
+        (partial, total)
+    };
+
+    let smooth = RenderOptions::default();
+    let aliased = RenderOptions { anti_alias: false, ..smooth };
+    let (smooth_partial, smooth_total) = coverage(&smooth);
+    let (aliased_partial, aliased_total) = coverage(&aliased);
+    assert!(smooth_partial > 0);
+    assert_eq!(aliased_partial, 0);
+    assert!(smooth_total.abs_diff(aliased_total) < smooth_total / 10);
+
+    // Invalid gammas are ignored instead of producing garbage coverage.
+    let expected = render(&smooth);
+    for text_gamma in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        let pixmap = render(&RenderOptions { text_gamma, ..smooth });
+        assert_eq!(pixmap.data(), expected.data());
+    }
+}
+
+#[test]
+fn test_contact_sheet() {
+    let data = fs::read(font("LinLibertine_R.ttf")).unwrap();
+    let libertine = Font::new(data.into(), 0).unwrap();
+    let frames = vec![Frame::new(Size::new(Abs::pt(50.0), Abs::pt(75.0))); 5];
+
+    // Thumbnails are 100 x 150 pixels with gaps of 10 pixels. Labels take
+    // 1.6 times their size, which is a twelfth of the thumbnail width.
+    let size = |columns, label_font: Option<&Font>| {
+        let sheet = ContactSheet {
+            columns: NonZeroUsize::new(columns).unwrap(),
+            thumbnail_width: 100.0,
+            gap: 10.0,
+            label_font: label_font.cloned(),
+            ..ContactSheet::default()
+        };
+        let pixmap = typst::export::render_contact_sheet(&frames, &sheet);
+        (pixmap.width(), pixmap.height())
+    };
+
+    assert_eq!(size(1, None), (120, 810));
+    assert_eq!(size(2, None), (230, 490));
+    assert_eq!(size(4, None), (450, 330));
+    assert_eq!(size(8, None), (560, 170));
+    assert_eq!(size(2, Some(&libertine)), (230, 530));
+    assert_eq!(size(4, Some(&libertine)), (450, 357));
+}
+
+#[test]
+fn test_pattern() {
+    // A tile whose top-left quarter is black fills the square as a checkerboard.
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 40pt, height: 40pt, margin: 0pt)\n\
+         #let quarter = rect(width: 10pt, height: 10pt, fill: black)\n\
+         #let checkers = pattern(size: (20pt, 20pt), quarter)\n\
+         #rect(width: 40pt, height: 40pt, stroke: none, fill: checkers)",
+    );
+    let pixmap = typst::export::render(&document.pages[0], 1.0, Color::WHITE);
+    let black = |x, y| pixmap.pixel(x, y).unwrap().red() == 0;
+    for (x, y) in [(5, 5), (25, 5), (5, 25), (25, 25)] {
+        assert!(black(x, y));
+    }
+    for (x, y) in [(15, 5), (5, 15), (15, 15), (35, 35)] {
+        assert!(!black(x, y));
+    }
+
+    let pdf = typst::export::pdf(&document, &mut Tracer::default());
+    assert_eq!(count(&pdf, b"/PatternType 1"), 1);
+    assert!(contains(&pdf, b"/XStep 20"));
+
+    // Text filled with red and blue stripes only shows those two colors.
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 120pt, height: 40pt, margin: 0pt)\n\
+         #let stripes = pattern(stack(\n\
+           dir: ltr,\n\
+           rect(width: 4pt, height: 4pt, fill: red),\n\
+           rect(width: 4pt, height: 4pt, fill: blue),\n\
+         ))\n\
+         #text(size: 32pt, fill: stripes)[HHH]",
+    );
+    let pixmap = typst::export::render(&document.pages[0], 2.0, Color::WHITE);
+    let pixels = pixmap.pixels();
+    assert!(pixels.iter().any(|p| p.red() > 200 && p.blue() < 60));
+    assert!(pixels.iter().any(|p| p.blue() > 200 && p.red() < 60));
+    assert!(!pixels.iter().any(|p| p.red() < 128 && p.blue() < 128));
+}
+
+#[test]
+fn test_compile_content() {
+    let world = FontWorld::new();
+    let document = world.compile(&sequence([
+        heading(1, text("Report")),
+        text("All systems nominal."),
+        parbreak(),
+        table(2, [text("CPU"), text("12%"), text("Memory"), text("3 GB")]),
+        pagebreak(),
+        list([text("One"), text("Two")]),
+    ]));
+
+    assert_eq!(document.pages.len(), 2);
+    let first = text_in(&document.pages[0]);
+    assert!(first.contains("Report") && first.contains("Memory"));
+    assert!(text_in(&document.pages[1]).contains("Two"));
+}
+
+#[test]
+fn test_at_width() {
+    let world = FontWorld::new();
+    let body = text("Hello World");
+    let size = |pt| {
+        let document = world.compile(&at_width(body.clone(), Abs::pt(pt)));
+        assert_eq!(document.pages.len(), 1);
+        document.pages[0].size()
+    };
+
+    // The text fits on one line at the wider width, but breaks at the
+    // narrower one, so that the page grows.
+    let wide = size(200.0);
+    let narrow = size(40.0);
+    assert_eq!(wide.x, Abs::pt(200.0));
+    assert_eq!(narrow.x, Abs::pt(40.0));
+    assert!(narrow.y > wide.y * 1.5);
+    assert_eq!(size(100.0).y, wide.y);
+}
+
+#[test]
+fn test_glyph_outlines() {
+    let data = fs::read(font("LinLibertine_R.ttf")).unwrap();
+    let libertine = Font::new(data.into(), 0).unwrap();
+    let glyph = |c| libertine.ttf().glyph_index(c).unwrap().0;
+    let size = Abs::pt(10.0);
+
+    // The outline sits on the baseline, with the y-axis pointing down.
+    let path = libertine.outline(glyph('A'), size).unwrap();
+    assert!(!path.0.is_empty());
+    assert!(path.0.iter().all(|item| match item {
+        PathItem::MoveTo(p) | PathItem::LineTo(p) | PathItem::CubicTo(_, _, p) => {
+            p.x >= -size && p.x <= size && p.y >= -size && p.y <= Abs::pt(0.5)
+        }
+        PathItem::ClosePath => true,
+    }));
+    assert_eq!(libertine.outline(glyph(' '), size), None);
+
+    // Spaces are skipped in the outlines of a text item.
+    let world = FontWorld::new();
+    let document = world.compile(&text("A B"));
+    let item = first_text(&document.pages[0]).unwrap();
+    let outlines = item.outlines();
+    assert_eq!(outlines.len(), 2);
+    assert_eq!(outlines[0].1, item.font.outline(item.glyphs[0].id, item.size).unwrap());
+    assert!(outlines[1].0.x > outlines[0].0.x + item.size * 0.5);
+}
+
+#[test]
+fn test_measure_text() {
+    let world = FontWorld::new();
+    let world_ref: &dyn World = &world;
+    let introspector = Introspector::default();
+    let mut locator = Locator::new();
+    let mut delayed = DelayedErrors::default();
+    let mut tracer = Tracer::default();
+    let vt = Vt {
+        world: world_ref.track(),
+        introspector: introspector.track(),
+        locator: &mut locator,
+        delayed: delayed.track_mut(),
+        tracer: tracer.track_mut(),
+    };
+
+    // Every additional character, including the space, makes the text wider,
+    // but not higher.
+    let styles = StyleChain::new(&world.library.styles);
+    let text = "Hello World";
+    let sizes: Vec<_> = (0..=text.len())
+        .map(|end| measure_text(&vt, &text[..end], Span::detached(), styles).unwrap())
+        .collect();
+    assert_eq!(sizes[0].x, Abs::zero());
+    assert!(sizes.windows(2).all(|pair| pair[0].x < pair[1].x));
+    assert!(sizes[2..].iter().all(|size| size.y == sizes[1].y));
+}
+
+#[test]
+fn test_line_numbers() {
+    // The line numbers on each page. The body text contains no digits.
+    let numbers = |src: &str| -> Vec<Vec<usize>> {
+        let document = FontWorld::new().compile_source(src);
+        document
+            .pages
+            .iter()
+            .map(|page| {
+                text_items(page)
+                    .into_iter()
+                    .filter_map(|item| item.text.parse().ok())
+                    .collect()
+            })
+            .collect()
+    };
+
+    // Numbering continues when the text flows onto the next page and after
+    // an explicit page break.
+    let pages = numbers(
+        "#set page(height: 80pt, line-numbers: (start: 3))\n\
+         #lorem(40)\n\
+         #pagebreak()\n\
+         After the break.",
+    );
+    assert!(pages.len() >= 3);
+    assert!(pages.iter().all(|numbers| !numbers.is_empty()));
+    let all: Vec<usize> = pages.concat();
+    assert_eq!(all, (3..3 + all.len()).collect::<Vec<_>>());
+
+    // Different settings start over.
+    let pages = numbers(
+        "#set page(line-numbers: (start: 3))\n\
+         A\n\
+         #set page(line-numbers: (start: 10))\n\
+         B",
+    );
+    assert_eq!(pages, [vec![3], vec![10]]);
+}
+
+#[test]
+fn test_isolate() {
+    // The letters from left to right on the page.
+    let order = |body: &str| {
+        let src = format!(
+            "#set text(dir: rtl, font: (\"Linux Libertine\", \"Noto Serif Hebrew\"))\n\
+             {body}"
+        );
+        let document = FontWorld::new().compile_source(&src);
+        let mut letters: Vec<(Abs, char)> = placed_text(&document.pages[0])
+            .into_iter()
+            .flat_map(|(pos, item)| {
+                let mut x = pos.x;
+                item.glyphs.iter().map(move |glyph| {
+                    let c = item.text[glyph.range()].chars().next().unwrap();
+                    let at = x;
+                    x += glyph.x_advance.at(item.size);
+                    (at, c)
+                })
+            })
+            .filter(|(_, c)| c.is_alphabetic())
+            .collect();
+        letters.sort_by(|a, b| a.0.cmp(&b.0));
+        letters.into_iter().map(|(_, c)| c).collect::<String>()
+    };
+
+    // Without isolation, the Latin letters are separate runs within the
+    // right-to-left paragraph. Isolated, the run is ordered left to right as a
+    // whole and placed in the paragraph like a single word.
+    assert_eq!(order("א a ב c ז"), "זcבaא");
+    assert_eq!(order("א #isolate(ltr)[a ב c] ז"), "זaבcא");
+}
+
+#[test]
+fn test_align_frame() {
+    let world = FontWorld::new();
+    typst::eval::set_lang_items(world.library.items.clone());
+
+    let frame = Frame::new(Size::new(Abs::pt(20.0), Abs::pt(10.0)));
+    let region = Size::new(Abs::pt(100.0), Abs::pt(50.0));
+    let base = StyleChain::new(&world.library.styles);
+    let position = |x: Option<Align>, y: Option<Align>| {
+        let alignment = Axes::new(x.map(GenAlign::from), y.map(GenAlign::from));
+        let local = Styles::from(AlignElem::set_alignment(alignment));
+        align_frame(&frame, region, base.chain(&local))
+    };
+
+    // Without an explicit alignment, frames sit at the start and top. An
+    // alignment along one axis keeps the inherited one along the other.
+    assert_eq!(align_frame(&frame, region, base), Point::zero());
+    assert_eq!(position(Some(Align::Right), None), Point::with_x(Abs::pt(80.0)));
+    assert_eq!(
+        position(Some(Align::Center), Some(Align::Bottom)),
+        Point::new(Abs::pt(40.0), Abs::pt(40.0)),
+    );
+}
+
+#[test]
+fn test_hyphenation_penalty() {
+    // For each line, whether it ends with an inserted hyphen, which is the
+    // only glyph without text.
+    let hyphens = |settings: &str| {
+        let src = format!(
+            "#set page(width: 80pt)\n\
+             #set par(justify: true, {settings})\n\
+             #set text(hyphenate: true)\n\
+             #lorem(60)"
+        );
+        let document = FontWorld::new().compile_source(&src);
+        document
+            .pages
+            .iter()
+            .flat_map(text_items)
+            .map(|item| item.glyphs.last().map_or(false, |g| g.range.is_empty()))
+            .collect::<Vec<_>>()
+    };
+
+    let count = |lines: &[bool]| lines.iter().filter(|&&hyphen| hyphen).count();
+    let cheap = hyphens("hyphenation-penalty: 0");
+    let expensive = hyphens("hyphenation-penalty: 10000");
+    assert!(count(&cheap) > count(&expensive));
+
+    let limited = hyphens("hyphenation-penalty: 0, consecutive-hyphens: 1");
+    assert!(count(&limited) > 0);
+    assert!(!limited.windows(2).any(|pair| pair[0] && pair[1]));
+}
+
+#[test]
+fn test_document_stats() {
+    // Words split into differently styled parts are counted once, while
+    // words on separate lines stay separate.
+    let document = FontWorld::new().compile_source(
+        "#set page(width: 60pt)\n\
+         Hello wo#text(red)[rld] and *bold*ly written text",
+    );
+    let stats = document.stats();
+    assert_eq!(stats.pages, 1);
+    assert_eq!(stats.text.words, 6);
+}
@@ -29,6 +29,12 @@
 // Trailing comma.
 #test(1 + 1, 2,)
 
+// Newlines between arguments.
+#let g(columns: 1, gutter: 0pt) = (columns, gutter)
+#test(g(columns: 3,
+  gutter: 4pt,
+), (3, 4pt))
+
 // Call function assigned to variable.
 #let alias = type
 #test(alias(alias), "function")
@@ -43,6 +49,12 @@
   test(adder(2)(5), 7)
 }
 
+---
+// Content blocks as named arguments.
+#let f(caption: none, body) = (caption, body)
+#test(f(caption: [*Bold* caption])[Body], ([*Bold* caption], [Body]))
+#test(f(caption: [*A*], [B]).first().func(), strong)
+
 ---
 // Error: 26-30 duplicate argument: font
 #set text(font: "Arial", font: "Helvetica")
@@ -24,3 +24,47 @@
 #test(luma(20%).lighten(50%), luma(60%))
 #test(luma(80%).darken(20%), luma(63.9%))
 #test(luma(80%).negate(), luma(20%))
+
+---
+// Test named CSS colors.
+// Ref: false
+#test(color.css.navy, rgb("#000080"))
+#test(color.css.rebeccapurple, rgb(102, 51, 153))
+
+---
+// Error: 23-28 module `css` does not contain `navvy`
+#text(fill: color.css.navvy)[A]
+
+---
+// Test saturation and mixing.
+// Ref: false
+#test(luma(50%).saturate(50%), luma(50%))
+#test(type(red.desaturate(20%)), "color")
+#test(color.mix(black, white, space: "srgb"), rgb("#808080"))
+
+---
+// Test the saturation methods.
+// Ref: false
+#let close(a, b) = range(4).all(i => calc.abs(a.rgba().at(i) - b.rgba().at(i)) <= 1)
+#let spread(c) = {
+  let (r, g, b, a) = c.rgba()
+  calc.max(r, g, b) - calc.min(r, g, b)
+}
+#let muted = rgb("#806060")
+#test(spread(red.desaturate(100%)) <= 1, true)
+#test(red.desaturate(100%).rgba().at(3), 255)
+#test(spread(muted.saturate(50%)) > spread(muted), true)
+#test(spread(muted.desaturate(50%)) < spread(muted), true)
+#test(close(muted.saturate(0%), muted), true)
+#test(close(muted.desaturate(0%), muted), true)
+#test(close(muted.saturate(25%).desaturate(20%), muted), true)
+#test(close(muted.desaturate(100%).saturate(100%), muted.desaturate(100%)), true)
+#test(cmyk(0%, 0%, 0%, 50%).desaturate(100%).kind(), rgb)
+
+---
+// Error: 17-21 factor must not be negative
+#red.desaturate(-10%)
+
+---
+// Error: 15-19 factor must not be negative
+#red.saturate(-10%)
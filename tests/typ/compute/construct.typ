@@ -72,6 +72,29 @@
 // Error: 31-38 expected "oklab" or "srgb"
 #color.mix(red, green, space: "cyber")
 
+---
+// Test linear gradients.
+#test(type(gradient.linear(red, blue)), "gradient")
+#test(gradient.linear(red, blue), gradient.linear((red, 0%), (blue, 100%)))
+#test(gradient.linear(red, green, blue), gradient.linear(red, (green, 50%), blue))
+#test(gradient.linear(red, blue) == gradient.linear(red, blue, angle: 90deg), false)
+
+---
+// Error: 17-22 a gradient must have at least two stops
+#gradient.linear(red)
+
+---
+// Error: 17-36 offset must be between 0% and 100%
+#gradient.linear(red, (blue, 120%))
+
+---
+// Error: 17-42 offsets must be in ascending order
+#gradient.linear((red, 50%), (blue, 20%))
+
+---
+// Error: 23-35 expected a color or color-offset pair
+#gradient.linear(red, (blue, 1, 2))
+
 ---
 // Ref: true
 #let envelope = symbol(
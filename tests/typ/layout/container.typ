@@ -48,3 +48,75 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// Test that the background is laid out at the block's final size.
+// Ref: false
+#block(width: 40pt, height: 30pt, background: layout(size => {
+  test(size, (width: 40pt, height: 30pt))
+}))
+
+#style(styles => {
+  let body = block(width: 40pt)[A]
+  let lined = block(width: 40pt, background: rect(height: 200pt))[A]
+  test(measure(lined, styles), measure(body, styles))
+})
+
+---
+// Test aligning boxes with the surrounding text.
+// Ref: false
+#style(styles => {
+  let edges(top, bottom) = {
+    measure(text(top-edge: top, bottom-edge: bottom)[x], styles).height
+  }
+  let ascender = edges("ascender", "baseline")
+  let x-height = edges("x-height", "baseline")
+  let descender = edges("baseline", "descender")
+
+  // The distance from the square's top to the baseline. The empty box puts
+  // the line's baseline 30pt below its top.
+  let baseline(align) = {
+    let line = [#box(height: 30pt)#box(baseline: align, square(size: 20pt))]
+    30pt - measure(line, styles, bounds: true).ink.y
+  }
+
+  let approx(a, b) = test(calc.abs((a - b) / 1pt) < 0.01, true)
+  approx(baseline(top), ascender)
+  approx(baseline(horizon), (20pt + x-height) / 2)
+  approx(baseline(bottom), 20pt - descender)
+})
+
+---
+// Error: 16-20 alignment must be vertical
+#box(baseline: left)[]
+
+---
+// Test measuring the baseline and ink bounds.
+// Ref: false
+#style(styles => {
+  let m = measure([x], styles, bounds: true)
+  test(m.ink.height < m.height, true)
+  test(m.ink.y > 0pt, true)
+  test(calc.abs((m.ink.y + m.ink.height - m.baseline) / 1pt) < 0.5, true)
+  test(measure(h(1em), styles, bounds: true).ink, none)
+})
+
+---
+// Test reporting unbreakable content that overflows its region.
+// Ref: false
+// Hints: false
+#set page(width: 100pt, height: 80pt)
+#set block(overflow-tolerance: 0pt)
+// Warning: 2-34 content overflows its region
+#rect(width: 120pt, height: 20pt)
+#rect(width: 40pt, height: 20pt)
+#block(height: 200pt)
+// Warning: 2-40 content overflows its region
+#block(breakable: false, height: 200pt)
+
+---
+// Test that overflowing content is not reported by default.
+// Ref: false
+#set page(width: 100pt, height: 80pt)
+#rect(width: 120pt, height: 20pt)
+#block(breakable: false, height: 200pt)
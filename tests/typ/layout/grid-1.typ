@@ -39,3 +39,16 @@
   align(center)[A bit more to the top],
   [],
 )
+
+---
+// Test repeating the header of a grid with gutters across pages.
+// Ref: false
+#set page(height: 80pt, margin: 10pt)
+#locate(loc => test(counter("header").final(loc), counter(page).final(loc)))
+#grid(
+  columns: 2,
+  header-rows: 1,
+  gutter: 4pt,
+  [*Head* #counter("header").step()], [*Value*],
+  ..range(12).map(i => ([Row #i], [#i])).flatten()
+)
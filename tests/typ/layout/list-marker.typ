@@ -32,3 +32,25 @@
 ---
 // Error: 19-21 array must contain at least one marker
 #set list(marker: ())
+
+---
+// Test task list items.
+// Ref: false
+- [ ] Open
+- [x] Done
+- [X] Also done
+- [] Not a task
+- [y] Not a task either
+#test(list.item(checked: true)[A].checked, true)
+
+---
+// Test custom checkbox markers.
+// Ref: false
+#set list(checkbox: ([○], [●]))
+- [ ] Open
+  - [x] Nested and done
+- Regular
+
+---
+// Error: 21-26 array must contain exactly two markers
+#set list(checkbox: ([],))
@@ -26,5 +26,46 @@ Hi #box(pad(left: 10pt)[A]) there
 #align(right)[After]
 
 ---
-// Test that padding adding up to 100% does not panic.
+// Test that padding of almost 100% around nothing is empty.
+#pad(45%)[]
+
+---
+// Test that padding adding up to 100% is an error instead of a panic.
+// Ref: false
+// Error: 2-12 relative padding must add up to less than 100%
+// Hint: 2-12 the horizontal padding adds up to 100%
 #pad(50%)[]
+
+---
+// Test that padding adding up to more than 100% is an error.
+// Ref: false
+// Error: 2-31 relative padding must add up to less than 100%
+// Hint: 2-31 the horizontal padding adds up to 120%
+#pad(left: 60%, right: 60%)[A]
+
+---
+// Ref: false
+// Error: 2-40 relative padding must add up to less than 100%
+// Hint: 2-40 the vertical padding adds up to 110%
+#pad(y: 55%, image("/files/tiger.jpg"))
+
+---
+// Test negative padding.
+// Ref: false
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  test(measure(pad(-4pt, body), styles), (width: 12pt, height: 2pt))
+  test(measure(pad(x: -15pt, body), styles).width, 0pt)
+})
+
+---
+// Test padding relative to the page.
+// Ref: false
+#set page(width: 200pt)
+#style(styles => {
+  let body = rect(width: 20pt, height: 10pt)
+  let pagepad(body) = pad(x: 10%, relative: "page", body)
+  test(measure(pad(x: 10%, body), styles).width, 25pt)
+  test(measure(pagepad(body), styles).width, 60pt)
+  test(measure(pagepad(pagepad(body)), styles).width, 100pt)
+})
@@ -44,3 +44,36 @@
 ---
 // Error: 20-23 must be `left` or `right`
 #set page(binding: top)
+
+---
+// Test the binding offset.
+// Ref: false
+#set page(height: 100pt, width: 100pt, margin: (inside: 10pt, outside: 10pt, y: 10pt), binding-offset: 20pt)
+#locate(loc => test(loc.position().x, 30pt))
+#pagebreak()
+#locate(loc => test(loc.position().x, 10pt))
+#set page(margin: 10pt, binding-offset: 15pt)
+#locate(loc => test(loc.position().x, 25pt))
+
+---
+// Test that the binding offset stays on the bound side with one-sided
+// margins.
+// Ref: false
+#set page(height: 100pt, width: 100pt, margin: 10pt, binding-offset: 20pt)
+#locate(loc => test(loc.position().x, 30pt))
+#pagebreak()
+#locate(loc => test(loc.position().x, 30pt))
+#pagebreak()
+#locate(loc => test(loc.position().x, 30pt))
+
+---
+// Test the binding offset for right-bound documents.
+// Ref: false
+#set page(width: 100pt, margin: 10pt, binding: right, binding-offset: 20pt)
+#locate(loc => test(loc.position().x, 10pt))
+#pagebreak()
+#locate(loc => test(loc.position().x, 10pt))
+#set page(margin: (inside: 10pt, outside: 10pt, y: 10pt))
+#locate(loc => test(loc.position().x, 10pt))
+#pagebreak()
+#locate(loc => test(loc.position().x, 30pt))
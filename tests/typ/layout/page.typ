@@ -40,3 +40,13 @@
   h(1em)
   place(left, rect(width: 80pt, stroke: blue))
 })
+
+---
+// Test line numbering across pages.
+// Ref: false
+#set page(height: 80pt, line-numbers: (start: 3, step: 2, side: right))
+#lorem(40)
+
+---
+// Error: 25-39 line numbers must be placed on the left or right
+#set page(line-numbers: (side: center))
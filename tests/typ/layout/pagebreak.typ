@@ -43,3 +43,11 @@ First
 #page[Second]
 #pagebreak(weak: true)
 #page[Third]
+
+---
+// Test dropping the empty page after a trailing pagebreak.
+// Ref: false
+#set document(keep-empty-last: false)
+#locate(loc => test(counter(page).final(loc), (1,)))
+The end.
+#pagebreak()
@@ -53,3 +53,15 @@ Lריווח #h(1cm) R
 
 // Error: 16-19 text direction must be horizontal
 #set text(dir: ttb)
+
+---
+// Test isolating an embedded run.
+// Ref: false
+#set text(lang: "ar", font: ("Noto Sans Arabic", "Linux Libertine"))
+الملف #isolate(ltr)[`src/main.rs`] مفقود
+#set text(lang: "en")
+The name #isolate(rtl)[שלום 123] is Hebrew.
+
+---
+// Error: 10-13 text direction must be horizontal
+#isolate(ttb)[A]
@@ -40,3 +40,29 @@ This text can be fitted in one line.
 	"a b c "
 }
 #"d"
+
+---
+// Test aligning the last line of a justified paragraph.
+// Ref: false
+#style(styles => {
+  // The first line is an empty box without ink, so all ink belongs to the
+  // last line.
+  let b = box(width: 10pt, height: 5pt, fill: black)
+  let width = measure([#b #b], styles).width
+  let last(..args) = {
+    let body = block(width: 100pt, par(justify: true, ..args)[#box(width: 100%) #b #b])
+    measure(body, styles, bounds: true).ink
+  }
+  let approx(a, b) = test(calc.abs((a - b) / 1pt) < 0.01, true)
+
+  // The last line keeps its natural width instead of being stretched.
+  for (align, x) in ((auto, 0pt), (center, (100pt - width) / 2), (end, 100pt - width)) {
+    let ink = last(last-line: align)
+    approx(ink.width, width)
+    approx(ink.x, x)
+  }
+})
+
+---
+// Error: 21-24 alignment must be horizontal
+#set par(last-line: top)
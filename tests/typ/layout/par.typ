@@ -34,3 +34,36 @@ fn main() {}
 - List
 
 Paragraph
+
+---
+// Test that lines are at least as tall as the minimum line height and at
+// least as tall as their tallest content.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(block(width: 100pt, body), styles).height
+  let short = height[#set par(min-line-height: 30pt); A]
+  test(short > 29.99pt and short < 30.01pt, true)
+  test(height[A #text(30pt)[B] C] > height[A B C], true)
+  test(height[#set par(min-line-height: 1pt); A], height[A])
+})
+
+---
+// Test reporting badly filled lines.
+// Ref: false
+// Hints: false
+#set page(width: 60pt)
+#set text(hyphenate: false)
+#set par(justify: true, overfull-tolerance: 1pt, underfull-tolerance: 100%)
+// Warning: 1-38 line is overfull
+// Warning: 1-38 line is underfull
+Hi Supercalifragilisticexpialidocious
+
+---
+// Test that bad lines within the same text are reported once.
+// Ref: false
+// Hints: false
+#set page(width: 60pt)
+#set text(hyphenate: false)
+#set par(overfull-tolerance: 1pt)
+// Warning: 1-70 line is overfull
+Supercalifragilisticexpialidocious Supercalifragilisticexpialidocious
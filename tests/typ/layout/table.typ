@@ -36,3 +36,128 @@
 ---
 // Error: 14-19 expected color, none, array, or function, found string
 #table(fill: "hey")
+
+---
+// Test aligning numbers on their decimal point.
+// Ref: false
+#table(
+  columns: 2,
+  align: (left, "decimal"),
+  [Apples], [1.5],
+  [Pears], [12.25],
+  [Plums], [100],
+)
+
+---
+// Test that decimal alignment pads numbers to their widest parts.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let numbers = table(inset: 0pt, stroke: none, align: "decimal", [1.5], [12.25], [100])
+  test(calc.abs((width(numbers) - width[100.25]) / 1pt) < 0.01, true)
+})
+
+---
+// Test that decimal alignment measures numbers with their own styles.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let numbers = table(
+    inset: 0pt,
+    stroke: none,
+    align: "decimal",
+    text(size: 40pt)[1.5],
+    [12.25],
+  )
+  let big = width(text(size: 40pt)[1.5])
+  test(calc.abs((width(numbers) - big) / 1pt) < 0.01, true)
+})
+
+---
+// Test that header rows repeat on every page.
+// Ref: false
+#set page(height: 100pt, margin: 10pt)
+#locate(loc => test(counter("header").final(loc), counter(page).final(loc)))
+#table(
+  columns: 2,
+  header-rows: 1,
+  [*Name* #counter("header").step()], [*Value*],
+  ..range(12).map(i => ([Item #i], [#i])).flatten()
+)
+
+---
+// Test cells that span multiple columns.
+// Ref: false
+#table(
+  columns: 3,
+  [Name], table.cell(colspan: 2)[Score],
+  [], [Day 1], [Day 2],
+  [Anna], [3], [5],
+)
+
+---
+// Test that a spanning cell is as wide as its columns and that spans are
+// clamped to the end of the row.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let spanned = grid(
+    columns: (20pt, 30pt, 40pt),
+    column-gutter: 5pt,
+    grid.cell(colspan: 2, box(width: 100%)[]), grid.cell(colspan: 4)[],
+    [A], [B], [C],
+  )
+  test(width(spanned), 100pt)
+})
+
+---
+// Test that a spanning cell grows the auto columns it spans.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let wide = grid.cell(colspan: 2, box(width: 60pt))
+  test(width(grid(columns: 2, wide, box(width: 10pt), box(width: 20pt))), 60pt)
+  let gutter = grid(
+    columns: 2,
+    column-gutter: 10pt,
+    wide, box(width: 10pt), box(width: 20pt),
+  )
+  test(width(gutter), 60pt)
+  test(width(grid(columns: 2, wide, box(width: 50pt), box(width: 20pt))), 70pt)
+})
+
+---
+// Test stroking only some sides and per-column strokes.
+// Ref: false
+#table(columns: 2, stroke: (bottom: 1pt + red, x: none), [A], [B])
+#table(columns: 2, stroke: (1pt, none), [A], [B])
+
+---
+// Test fills and strokes on grids.
+// Ref: false
+#grid(
+  columns: 2,
+  gutter: 4pt,
+  fill: (x, y) => if y == 0 { aqua },
+  stroke: (x, y) => (bottom: if y == 0 { 1pt }),
+  [A], [B],
+  [C], [D],
+)
+
+---
+// Test that fill and stroke functions receive cell coordinates with gutters.
+// Ref: false
+#table(
+  columns: 3,
+  gutter: 2pt,
+  fill: (x, y) => {
+    assert(x < 3 and y < 2)
+    if calc.even(x + y) { luma(230) }
+  },
+  stroke: (x, y) => {
+    assert(x < 3 and y < 2)
+    (bottom: if y == 0 { 1pt })
+  },
+  table.cell(colspan: 2)[A], [B],
+  [C], [D], [E],
+)
@@ -50,3 +50,23 @@ Not in list
 ---
 // Error: 8 expected colon
 / Hello
+
+---
+// Test aligning descriptions to a tab stop.
+// Ref: false
+#style(styles => {
+  // The term is an empty box, so all ink belongs to the description.
+  let start(term-width, ..args) = {
+    let item = terms.item(box(width: term-width), rect(width: 5pt, height: 5pt))
+    let list = block(width: 200pt, terms(indent: 10pt, ..args, item))
+    measure(list, styles, bounds: true).ink.x
+  }
+
+  // With `auto`, the description starts 1em (11pt) after the widest term.
+  test(start(30pt, tab-stop: auto), 51pt)
+  test(start(50pt, tab-stop: auto), 71pt)
+
+  // With a length, it starts at a fixed distance from the indent.
+  test(start(30pt, tab-stop: 40pt), 50pt)
+  test(start(50pt, tab-stop: 40pt), 50pt)
+})
@@ -9,3 +9,17 @@ $ phi.alt := (1 + sqrt(5)) / 2 $ <ratio>
 
 With @ratio, we get
 $ F_n = round(1 / sqrt(5) phi.alt^n) $ <fib>
+
+---
+// Test that only numbered equations step the counter.
+// Ref: false
+#set math.equation(numbering: "(1)")
+$ a^2 + b^2 = c^2 $ <pythag>
+$ e^(i pi) + 1 = 0 $ <euler>
+#set math.equation(numbering: none)
+$ x = y $
+#locate(loc => {
+  test(counter(math.equation).at(query(<pythag>, loc).first().location()), (1,))
+  test(counter(math.equation).at(query(<euler>, loc).first().location()), (2,))
+  test(counter(math.equation).final(loc), (2,))
+})
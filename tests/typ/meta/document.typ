@@ -10,6 +10,16 @@ What's up?
 // Ref: false
 #set document(author: ("A", "B"))
 
+---
+// Keywords, the subject and the creation date, too.
+// Ref: false
+#set document(keywords: ("typst", "test"), date: datetime(year: 2023, month: 9, day: 4))
+#set document(subject: "Testing document metadata")
+
+---
+// Error: 25-27 expected string or array, found integer
+#set document(keywords: 10)
+
 ---
 // This, too.
 // Error: 23-29 expected string, found integer
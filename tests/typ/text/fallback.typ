@@ -18,3 +18,43 @@ Aب😀🏞سمB
 
 // Tofus are rendered with the first font.
 A🐈ዲሞB
+
+---
+// Missing glyphs can be replaced or skipped.
+// Ref: false
+#set text(missing: "replace")
+A🐈ዲሞB
+
+#set text(missing: "skip")
+#style(styles => {
+  test(measure([x\u{12F2}x], styles), measure([xx], styles))
+})
+
+---
+#set text(missing: "error")
+// Error: 1-9 current font does not support the character 'ዲ'
+\u{12F2}
+
+---
+// Mapped scripts use their families even if the regular list covers them.
+// Ref: false
+#style(styles => {
+  let mapped = text(script-fonts: (latn: "IBM Plex Sans"))[Hello 123]
+  let direct = text(font: "IBM Plex Sans")[Hello 123]
+  test(measure(mapped, styles), measure(direct, styles))
+})
+
+---
+// Error: 25-49 expected three or four letter script code (ISO 15924 or 'math')
+#set text(script-fonts: (latin: "IBM Plex Sans"))
+
+---
+// Missing glyphs are also errors outside of paragraphs.
+#set text(missing: "error")
+// Error: 2-21 current font does not support the character 'ዲ'
+#textpath[x\u{12F2}]
+
+---
+#set text(missing: "error")
+// Error: 2-3 current font does not support the character 'ዲ'
+$ዲ$
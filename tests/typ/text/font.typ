@@ -58,3 +58,15 @@ Emoji: 🐪, 🌋, 🏞
 ---
 // Error: 11-31 unexpected argument: something
 #set text(something: "invalid")
+
+---
+// Test which font face is selected for a variant.
+// Ref: false
+#let face = text.face("Linux Libertine", style: "italic", weight: "bold")
+#test(face.family, "Linux Libertine")
+#test(face.style, "italic")
+#test(face.weight, "bold")
+#test(face.exact, true)
+#test(text.face("linux libertine", weight: 900).weight, "bold")
+#test(text.face("linux libertine", weight: 900).exact, false)
+#test(text.face("Unknown Family"), none)
@@ -49,3 +49,23 @@ It's a #emph[Tree]beard.
 #set page(width: 60pt)
 #set text(hyphenate: true)
 #h(6pt) networks, the rest.
+
+---
+// Test tuning how often hyphenation is used.
+// Ref: false
+#set page(width: 80pt)
+#set par(justify: true, hyphenation-penalty: 10000, consecutive-hyphens: 1)
+#set text(hyphenate: true)
+#lorem(20)
+
+---
+// Test that hyphenation exceptions override the patterns.
+// Ref: false
+#style(styles => {
+  let height(..exceptions) = measure(block(width: 25pt, text(
+    hyphenate: true,
+    hyphenation-exceptions: exceptions.pos(),
+  )[Unbelievable]), styles).height
+  test(height("unbelievable") < height(), true)
+  test(height("UN-believable") > height("unbelievable"), true)
+})
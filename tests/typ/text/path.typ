@@ -0,0 +1,33 @@
+// Test setting text along a path.
+
+---
+// Ref: false
+#textpath(radius: 1.5cm)[Certified Quality Seal]
+#text(fill: red, textpath(radius: 1cm, angle: 180deg)[Bottom])
+#textpath(angle: -20deg)[Upwards]
+#textpath(angle: 200deg)[Backwards]
+
+---
+// Test that the arc is as large as its circle plus the text above it.
+// Ref: false
+#style(styles => {
+  let size = measure(textpath(radius: 20pt)[Round], styles)
+  test(size.width > 40pt, true)
+  test(size.width, size.height)
+})
+
+---
+// Test that a straight path is as large as its text.
+// Ref: false
+#style(styles => {
+  let path = measure(textpath[Flat], styles)
+  let plain = measure([Flat], styles)
+  test(path.height > 0pt, true)
+  test(calc.abs((path.width - plain.width) / 1pt) < 0.01, true)
+  test(calc.abs((path.height - plain.height) / 1pt) < 0.01, true)
+  test(measure(textpath(angle: 90deg)[Flat], styles).height > path.width - 0.01pt, true)
+})
+
+---
+// Error: 2-31 radius must be positive
+#textpath(radius: 0pt)[Broken]
@@ -29,3 +29,16 @@ My text has spaces.
 // Test word spacing relative to the font's space width.
 #set text(spacing: 50% + 1pt)
 This is tight.
+
+---
+// Test sentence spacing.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let wide(body) = width(text(sentence-spacing: 300%, body))
+  test(wide[One. Two] > width[One. Two], true)
+  test(wide[Yes! No? Ok] > width[Yes! No? Ok], true)
+  test(wide[J. Doe], width[J. Doe])
+  test(wide[See e.g. this], width[See e.g. this])
+  test(wide[Word, word], width[Word, word])
+})
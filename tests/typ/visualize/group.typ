@@ -0,0 +1,28 @@
+// Test opacity groups.
+
+---
+// Ref: false
+#set page(width: 120pt, height: auto)
+#let shapes = stack(
+  dir: ltr,
+  spacing: -20pt,
+  circle(radius: 20pt, fill: blue),
+  circle(radius: 20pt, fill: red),
+)
+
+#shapes
+#group(opacity: 50%, shapes)
+#group(opacity: 0%, shapes)
+
+---
+// Ref: false
+// A group doesn't affect layout.
+#style(styles => {
+  let size = measure(group(opacity: 30%, square(size: 20pt)), styles)
+  test(size.width, 20pt)
+  test(size.height, 20pt)
+})
+
+---
+// Error: 2-29 opacity must be between 0% and 100%
+#group(opacity: 150%)[Hello]
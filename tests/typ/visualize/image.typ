@@ -80,3 +80,17 @@ A #box(image("/files/tiger.jpg", height: 1cm, width: 80%)) B
 ---
 // Error: 2-83 failed to decode image
 #image.decode(read("/files/tiger.jpg", encoding: none), format: "png", width: 80%)
+
+---
+// Ref: false
+// Test embedding pages of a PDF file.
+#style(styles => {
+  let first = measure(image("/files/cover.pdf"), styles)
+  test((first.width, first.height), (200pt, 100pt))
+  let second = measure(image("/files/cover.pdf", page: 2), styles)
+  test((second.width, second.height), (100pt, 150pt))
+})
+
+---
+// Error: 2-36 pdf file has no page 3
+#image("/files/cover.pdf", page: 3)
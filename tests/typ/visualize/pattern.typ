@@ -0,0 +1,24 @@
+// Test tiling patterns.
+// Ref: false
+
+---
+#let hatched = pattern(size: (10pt, 10pt), line(start: (0%, 100%), end: (100%, 0%)))
+#test(type(hatched), "pattern")
+#test(hatched == hatched, true)
+#test(pattern[A] == pattern(spacing: (2pt, 2pt))[A], false)
+
+// Patterns are accepted by fills and strokes.
+#rect(width: 40pt, height: 20pt, fill: hatched, stroke: hatched)
+#text(fill: pattern(size: (4pt, 4pt), square(size: 2pt)))[Patterned text]
+
+---
+// Error: 9-31 pattern tile size must be non-zero
+#pattern(size: (0pt, 10pt))[A]
+
+---
+// Error: 16-31 point array must contain exactly two entries
+#pattern(size: (1pt, 2pt, 3pt))[A]
+
+---
+// Error: 9-31 pattern dimensions must be absolute lengths
+#pattern(size: (50%, 10pt))[A]